@@ -162,6 +162,27 @@ pub fn register_git_tools(builder: &mut McpServerBuilder) -> &mut McpServerBuild
     builder.add_tool(Arc::new(tools::GitTimeFilteredLogTool));
     builder.add_tool(Arc::new(tools::GitAddTool));
     builder.add_tool(Arc::new(tools::GitResetTool));
+    builder.add_tool(Arc::new(tools::GitFileHistoryTool));
+    builder.add_tool(Arc::new(tools::GitBlameTool));
+    builder.add_tool(Arc::new(tools::GitBranchTool));
+    builder.add_tool(Arc::new(tools::GitSwitchTool));
+    builder.add_tool(Arc::new(tools::GitStashTool));
+    builder.add_tool(Arc::new(tools::GitTagTool));
+    builder.add_tool(Arc::new(tools::GitFetchTool));
+    builder.add_tool(Arc::new(tools::GitMergeTool));
+    builder.add_tool(Arc::new(tools::GitConflictsListTool));
+    builder.add_tool(Arc::new(tools::GitConflictShowTool));
+    builder.add_tool(Arc::new(tools::GitConflictResolveTool));
+    builder.add_tool(Arc::new(tools::GitMergePreviewTool));
+    builder.add_tool(Arc::new(tools::GitRebaseTool));
+    builder.add_tool(Arc::new(tools::GitCherryPickTool));
+    builder.add_tool(Arc::new(tools::GitRevertTool));
+    builder.add_tool(Arc::new(tools::GitCloneTool));
+    builder.add_tool(Arc::new(tools::GitInitTool));
+    builder.add_tool(Arc::new(tools::GitRemoteTool));
+    builder.add_tool(Arc::new(tools::GitShowTool));
+    builder.add_tool(Arc::new(tools::GitFileAtRevTool));
+    builder.add_tool(Arc::new(tools::GitLsTool));
     builder
 }
 
@@ -178,5 +199,26 @@ pub fn get_all_git_tools() -> Vec<Arc<dyn ToolHandler>> {
         Arc::new(tools::GitTimeFilteredLogTool),
         Arc::new(tools::GitAddTool),
         Arc::new(tools::GitResetTool),
+        Arc::new(tools::GitFileHistoryTool),
+        Arc::new(tools::GitBlameTool),
+        Arc::new(tools::GitBranchTool),
+        Arc::new(tools::GitSwitchTool),
+        Arc::new(tools::GitStashTool),
+        Arc::new(tools::GitTagTool),
+        Arc::new(tools::GitFetchTool),
+        Arc::new(tools::GitMergeTool),
+        Arc::new(tools::GitConflictsListTool),
+        Arc::new(tools::GitConflictShowTool),
+        Arc::new(tools::GitConflictResolveTool),
+        Arc::new(tools::GitMergePreviewTool),
+        Arc::new(tools::GitRebaseTool),
+        Arc::new(tools::GitCherryPickTool),
+        Arc::new(tools::GitRevertTool),
+        Arc::new(tools::GitCloneTool),
+        Arc::new(tools::GitInitTool),
+        Arc::new(tools::GitRemoteTool),
+        Arc::new(tools::GitShowTool),
+        Arc::new(tools::GitFileAtRevTool),
+        Arc::new(tools::GitLsTool),
     ]
 }
@@ -0,0 +1,255 @@
+use crate::tools::{run_git_command, unquote_path};
+use async_trait::async_trait;
+use mcp_core::handler::{ToolError, ToolHandler};
+use schemars::{JsonSchema, schema_for};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+/// Git blame tool implementation
+#[derive(Debug, Default)]
+pub struct GitBlameTool;
+
+#[derive(Deserialize, JsonSchema)]
+struct GitBlameToolParams {
+    #[schemars(description = "The path to the git repository")]
+    repo_path: String,
+    #[schemars(description = "The file to blame")]
+    path: String,
+    #[schemars(description = "The revision to blame at (defaults to the working tree)")]
+    #[serde(default)]
+    rev: String,
+    #[schemars(description = "The first line to blame (1-based)")]
+    #[serde(default)]
+    start_line: u32,
+    #[schemars(description = "The last line to blame (inclusive)")]
+    #[serde(default)]
+    end_line: u32,
+    #[schemars(description = "A file listing revisions to ignore, e.g. .git-blame-ignore-revs")]
+    #[serde(default)]
+    ignore_revs_file: String,
+}
+
+/// Commit metadata printed the first time a commit appears in porcelain output
+#[derive(Debug, Clone, Default, Serialize)]
+struct BlameCommit {
+    author: String,
+    author_mail: String,
+    author_time: i64,
+    author_tz: String,
+    summary: String,
+    boundary: bool,
+    previous: Option<BlamePrevious>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct BlamePrevious {
+    commit: String,
+    path: String,
+}
+
+#[derive(Debug, Serialize)]
+struct BlameLine {
+    final_line: u32,
+    orig_line: u32,
+    content: String,
+}
+
+/// A run of consecutive lines attributed to the same commit
+#[derive(Debug, Serialize)]
+struct BlameGroup {
+    commit: String,
+    #[serde(flatten)]
+    info: BlameCommit,
+    orig_path: String,
+    lines: Vec<BlameLine>,
+}
+
+#[async_trait]
+impl ToolHandler for GitBlameTool {
+    fn name(&self) -> &'static str {
+        "git_blame"
+    }
+
+    fn description(&self) -> &'static str {
+        "Show which commit last changed each line of a file"
+    }
+
+    fn schema(&self) -> Value {
+        serde_json::to_value(schema_for!(GitBlameToolParams)).unwrap_or_default()
+    }
+
+    async fn call(&self, params: Value) -> Result<Value, ToolError> {
+        let params: GitBlameToolParams =
+            serde_json::from_value(params).map_err(|e| ToolError::ExecutionError(e.to_string()))?;
+
+        let rev = if params.rev.is_empty() {
+            None
+        } else {
+            Some(params.rev)
+        };
+
+        let start_line = if params.start_line == 0 {
+            None
+        } else {
+            Some(params.start_line)
+        };
+
+        let end_line = if params.end_line == 0 {
+            None
+        } else {
+            Some(params.end_line)
+        };
+
+        let ignore_revs_file = if params.ignore_revs_file.is_empty() {
+            None
+        } else {
+            Some(params.ignore_revs_file)
+        };
+
+        git_blame(
+            params.repo_path,
+            params.path,
+            rev,
+            start_line,
+            end_line,
+            ignore_revs_file,
+        )
+        .await
+    }
+}
+
+pub async fn git_blame(
+    repo_path: String,
+    path: String,
+    rev: Option<String>,
+    start_line: Option<u32>,
+    end_line: Option<u32>,
+    ignore_revs_file: Option<String>,
+) -> Result<Value, ToolError> {
+    let mut args = vec!["blame".to_string(), "--porcelain".to_string()];
+
+    // Handle line range, where either end may be left open
+    match (start_line, end_line) {
+        (Some(start), Some(end)) => args.push(format!("-L{},{}", start, end)),
+        (Some(start), None) => args.push(format!("-L{},", start)),
+        (None, Some(end)) => args.push(format!("-L1,{}", end)),
+        (None, None) => {}
+    }
+
+    if let Some(file) = &ignore_revs_file {
+        args.push(format!("--ignore-revs-file={}", file));
+    }
+
+    if let Some(rev) = &rev {
+        args.push(rev.clone());
+    }
+
+    args.push("--".to_string());
+    args.push(path.clone());
+
+    let cmd_args: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+    let blame_output = run_git_command(&repo_path, &cmd_args)?;
+
+    Ok(json!({
+        "path": path,
+        "rev": rev,
+        "groups": parse_blame_porcelain(&blame_output)
+    }))
+}
+
+/// Parse `blame --porcelain` output into line groups
+fn parse_blame_porcelain(output: &str) -> Vec<BlameGroup> {
+    let mut commits: HashMap<String, BlameCommit> = HashMap::new();
+    // git only repeats the filename when a commit touched more than one path
+    let mut paths: HashMap<String, String> = HashMap::new();
+    let mut groups: Vec<BlameGroup> = Vec::new();
+    // Commit, original line and final line of the header awaiting its content line
+    let mut pending: Option<(String, u32, u32)> = None;
+
+    for line in output.lines() {
+        if let Some(content) = line.strip_prefix('\t') {
+            let Some((commit, orig_line, final_line)) = pending.take() else {
+                continue;
+            };
+            let info = commits.get(&commit).cloned().unwrap_or_default();
+
+            // Groups are opened by headers that carry a line count
+            if let Some(group) = groups.last_mut()
+                && group.commit == commit
+            {
+                group.info = info;
+                group.orig_path = paths.get(&commit).cloned().unwrap_or_default();
+                group.lines.push(BlameLine {
+                    final_line,
+                    orig_line,
+                    content: content.to_string(),
+                });
+            }
+            continue;
+        }
+
+        let fields: Vec<&str> = line.split(' ').collect();
+        if is_blame_header(&fields) {
+            let commit = fields[0].to_string();
+            let orig_line = fields[1].parse().unwrap_or_default();
+            let final_line = fields[2].parse().unwrap_or_default();
+
+            commits.entry(commit.clone()).or_default();
+            if fields.len() == 4 {
+                groups.push(BlameGroup {
+                    commit: commit.clone(),
+                    info: BlameCommit::default(),
+                    orig_path: String::new(),
+                    lines: Vec::new(),
+                });
+            }
+            pending = Some((commit, orig_line, final_line));
+            continue;
+        }
+
+        // Remaining lines are `key value` metadata for the pending commit
+        let Some((commit, _, _)) = pending.as_ref() else {
+            continue;
+        };
+        let (key, value) = line.split_once(' ').unwrap_or((line, ""));
+        if key == "filename" {
+            paths.insert(commit.clone(), unquote_path(value));
+            continue;
+        }
+
+        let Some(info) = commits.get_mut(commit) else {
+            continue;
+        };
+        match key {
+            "author" => info.author = value.to_string(),
+            "author-mail" => {
+                info.author_mail = value
+                    .trim_start_matches('<')
+                    .trim_end_matches('>')
+                    .to_string()
+            }
+            "author-time" => info.author_time = value.parse().unwrap_or_default(),
+            "author-tz" => info.author_tz = value.to_string(),
+            "summary" => info.summary = value.to_string(),
+            "boundary" => info.boundary = true,
+            "previous" => {
+                info.previous = value.split_once(' ').map(|(commit, path)| BlamePrevious {
+                    commit: commit.to_string(),
+                    path: unquote_path(path),
+                })
+            }
+            _ => {}
+        }
+    }
+
+    groups
+}
+
+/// Whether a line is a `<oid> <orig line> <final line> [<count>]` header
+fn is_blame_header(fields: &[&str]) -> bool {
+    matches!(fields.len(), 3 | 4)
+        && (fields[0].len() == 40 || fields[0].len() == 64)
+        && fields[0].bytes().all(|b| b.is_ascii_hexdigit())
+        && fields[1..].iter().all(|f| f.parse::<u32>().is_ok())
+}
@@ -1,8 +1,9 @@
 use crate::tools::run_git_command;
 use mcp_core::handler::{ToolError, ToolHandler};
 use schemars::JsonSchema;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::{Value, json};
+use std::collections::HashSet;
 
 /// Git branches tool implementation
 #[derive(Default)]
@@ -12,6 +13,38 @@ pub struct GitBranchesTool;
 struct GitBranchesToolParam {
     #[schemars(description = "The path to the git repository")]
     repo_path: String,
+    #[schemars(description = "Whether to include remote-tracking branches")]
+    #[serde(default)]
+    remotes: bool,
+    #[schemars(description = "Only list branches whose name matches this glob, e.g. 'feature/*'")]
+    #[serde(default)]
+    pattern: String,
+    #[schemars(
+        description = "The for-each-ref sort key, e.g. 'refname' (default) or '-committerdate' for most recent first"
+    )]
+    #[serde(default)]
+    sort: String,
+    #[schemars(description = "Report whether each branch is merged into this commit or branch")]
+    #[serde(default)]
+    merged_into: String,
+}
+
+/// One local or remote-tracking branch
+#[derive(Debug, Serialize)]
+struct BranchInfo {
+    name: String,
+    #[serde(rename = "ref")]
+    refname: String,
+    remote: bool,
+    current: bool,
+    oid: String,
+    subject: String,
+    committer_date: String,
+    upstream: Option<String>,
+    ahead: Option<u32>,
+    behind: Option<u32>,
+    upstream_gone: bool,
+    merged: Option<bool>,
 }
 
 #[async_trait::async_trait]
@@ -33,26 +66,145 @@ impl ToolHandler for GitBranchesTool {
         let params: GitBranchesToolParam =
             serde_json::from_value(params).map_err(|e| ToolError::ExecutionError(e.to_string()))?;
 
-        git_branches(params.repo_path).await
+        let pattern = if params.pattern.is_empty() {
+            None
+        } else {
+            Some(params.pattern)
+        };
+
+        let sort = if params.sort.is_empty() {
+            None
+        } else {
+            Some(params.sort)
+        };
+
+        let merged_into = if params.merged_into.is_empty() {
+            None
+        } else {
+            Some(params.merged_into)
+        };
+
+        git_branches(params.repo_path, params.remotes, pattern, sort, merged_into).await
     }
 }
 
-pub async fn git_branches(repo_path: String) -> Result<Value, ToolError> {
-    let branch_output = run_git_command(&repo_path, &["branch", "--list"])?;
+/// Fields requested from `for-each-ref`, separated by NUL
+const BRANCH_FORMAT: &str = "--format=%(refname)%00%(refname:short)%00%(objectname)%00%(HEAD)%00%(symref)%00%(upstream:short)%00%(upstream:track,nobracket)%00%(committerdate:iso-strict)%00%(contents:subject)";
+
+pub async fn git_branches(
+    repo_path: String,
+    remotes: bool,
+    pattern: Option<String>,
+    sort: Option<String>,
+    merged_into: Option<String>,
+) -> Result<Value, ToolError> {
+    let mut namespaces = vec!["refs/heads/"];
+    if remotes {
+        namespaces.push("refs/remotes/");
+    }
+
+    // Patterns are matched against full ref names, so scope them to each namespace
+    let patterns: Vec<String> = namespaces
+        .iter()
+        .map(|ns| match &pattern {
+            Some(p) => format!("{}{}", ns, p),
+            None => ns.to_string(),
+        })
+        .collect();
+
+    let sort_arg = format!("--sort={}", sort.as_deref().unwrap_or("refname"));
+
+    let mut args = vec!["for-each-ref", BRANCH_FORMAT, &sort_arg];
+    args.extend(patterns.iter().map(|p| p.as_str()));
+
+    let branch_output = run_git_command(&repo_path, &args)?;
+
+    // Refs reachable from the base are merged into it
+    let merged: Option<HashSet<String>> = match &merged_into {
+        Some(base) => {
+            let merged_arg = format!("--merged={}", base);
+            let mut args = vec!["for-each-ref", "--format=%(refname)", &merged_arg];
+            args.extend(patterns.iter().map(|p| p.as_str()));
+
+            let merged_output = run_git_command(&repo_path, &args)?;
+            Some(merged_output.lines().map(str::to_string).collect())
+        }
+        None => None,
+    };
 
-    // Parse branches, trim whitespace and identify current branch with asterisk
-    let branches: Vec<String> = branch_output
+    let branches: Vec<BranchInfo> = branch_output
         .lines()
-        .map(|line| line.trim().to_string())
+        .filter_map(|line| parse_branch_line(line, merged.as_ref()))
         .collect();
 
-    // Get current branch separately
-    let current_branch = run_git_command(&repo_path, &["rev-parse", "--abbrev-ref", "HEAD"])?
-        .trim()
-        .to_string();
+    let current = branches
+        .iter()
+        .find(|branch| branch.current)
+        .map(|branch| branch.name.clone());
 
     Ok(json!({
         "branches": branches,
-        "current": current_branch
+        "current": current,
+        "merged_into": merged_into
     }))
 }
+
+fn parse_branch_line(line: &str, merged: Option<&HashSet<String>>) -> Option<BranchInfo> {
+    let fields: Vec<&str> = line.splitn(9, '\0').collect();
+    if fields.len() != 9 {
+        return None;
+    }
+
+    // Skip symbolic refs such as refs/remotes/origin/HEAD
+    if !fields[4].is_empty() {
+        return None;
+    }
+
+    let (ahead, behind, upstream_gone) = parse_track(fields[6]);
+    let upstream = if fields[5].is_empty() {
+        None
+    } else {
+        Some(fields[5].to_string())
+    };
+
+    // An up-to-date branch reports no track info, which means zero both ways
+    let (ahead, behind) = if upstream.is_some() && !upstream_gone {
+        (Some(ahead.unwrap_or(0)), Some(behind.unwrap_or(0)))
+    } else {
+        (None, None)
+    };
+
+    Some(BranchInfo {
+        name: fields[1].to_string(),
+        refname: fields[0].to_string(),
+        remote: fields[0].starts_with("refs/remotes/"),
+        current: fields[3] == "*",
+        oid: fields[2].to_string(),
+        subject: fields[8].to_string(),
+        committer_date: fields[7].to_string(),
+        ahead,
+        behind,
+        upstream,
+        upstream_gone,
+        merged: merged.map(|set| set.contains(fields[0])),
+    })
+}
+
+/// Parse `%(upstream:track,nobracket)`, e.g. "ahead 1, behind 2" or "gone"
+fn parse_track(track: &str) -> (Option<u32>, Option<u32>, bool) {
+    if track == "gone" {
+        return (None, None, true);
+    }
+
+    let mut ahead = None;
+    let mut behind = None;
+    for part in track.split(", ") {
+        if let Some(count) = part.strip_prefix("ahead ") {
+            ahead = count.parse().ok();
+        } else if let Some(count) = part.strip_prefix("behind ") {
+            behind = count.parse().ok();
+        }
+    }
+
+    (ahead, behind, false)
+}
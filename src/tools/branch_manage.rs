@@ -0,0 +1,189 @@
+use crate::tools::branch::git_branches;
+use crate::tools::run_git_command;
+use async_trait::async_trait;
+use mcp_core::handler::{ToolError, ToolHandler};
+use schemars::{JsonSchema, schema_for};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+/// Git branch management tool implementation
+#[derive(Debug, Default)]
+pub struct GitBranchTool;
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BranchAction {
+    Create,
+    Delete,
+    Rename,
+    SetUpstream,
+    UnsetUpstream,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct GitBranchToolParams {
+    #[schemars(description = "The path to the git repository")]
+    repo_path: String,
+    #[schemars(description = "The operation to perform")]
+    action: BranchAction,
+    #[schemars(description = "The branch to operate on")]
+    name: String,
+    #[schemars(
+        description = "The commit or branch to create the new branch from (defaults to HEAD)"
+    )]
+    #[serde(default)]
+    start_point: String,
+    #[schemars(description = "Whether to check out the branch after creating it")]
+    #[serde(default)]
+    checkout: bool,
+    #[schemars(description = "The new name when renaming")]
+    #[serde(default)]
+    new_name: String,
+    #[schemars(description = "The upstream branch to track, e.g. origin/main")]
+    #[serde(default)]
+    upstream: String,
+    #[schemars(
+        description = "Whether to delete an unmerged branch, or rename over an existing one (WARNING: may lose commits)"
+    )]
+    #[serde(default)]
+    force: bool,
+}
+
+#[async_trait]
+impl ToolHandler for GitBranchTool {
+    fn name(&self) -> &'static str {
+        "git_branch"
+    }
+
+    fn description(&self) -> &'static str {
+        "Create, delete or rename a branch, or set its upstream"
+    }
+
+    fn schema(&self) -> Value {
+        serde_json::to_value(schema_for!(GitBranchToolParams)).unwrap_or_default()
+    }
+
+    async fn call(&self, params: Value) -> Result<Value, ToolError> {
+        let params: GitBranchToolParams =
+            serde_json::from_value(params).map_err(|e| ToolError::ExecutionError(e.to_string()))?;
+
+        let start_point = if params.start_point.is_empty() {
+            None
+        } else {
+            Some(params.start_point)
+        };
+
+        let new_name = if params.new_name.is_empty() {
+            None
+        } else {
+            Some(params.new_name)
+        };
+
+        let upstream = if params.upstream.is_empty() {
+            None
+        } else {
+            Some(params.upstream)
+        };
+
+        git_branch(
+            params.repo_path,
+            params.action,
+            params.name,
+            start_point,
+            params.checkout,
+            new_name,
+            upstream,
+            params.force,
+        )
+        .await
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn git_branch(
+    repo_path: String,
+    action: BranchAction,
+    name: String,
+    start_point: Option<String>,
+    checkout: bool,
+    new_name: Option<String>,
+    upstream: Option<String>,
+    force: bool,
+) -> Result<Value, ToolError> {
+    if name.is_empty() {
+        return Err(ToolError::ExecutionError(
+            "A branch name is required".to_string(),
+        ));
+    }
+
+    let mut args: Vec<String> = Vec::new();
+
+    match action {
+        BranchAction::Create => {
+            if checkout {
+                args.extend(["switch".to_string(), "-c".to_string()]);
+            } else {
+                args.push("branch".to_string());
+            }
+            args.push(name.clone());
+            if let Some(start) = &start_point {
+                args.push(start.clone());
+            }
+        }
+        BranchAction::Delete => {
+            // `-d` refuses to delete branches that are not fully merged
+            let flag = if force { "-D" } else { "-d" };
+            args.extend(["branch".to_string(), flag.to_string(), name.clone()]);
+        }
+        BranchAction::Rename => {
+            let Some(new_name) = &new_name else {
+                return Err(ToolError::ExecutionError(
+                    "new_name is required to rename a branch".to_string(),
+                ));
+            };
+            let flag = if force { "-M" } else { "-m" };
+            args.extend([
+                "branch".to_string(),
+                flag.to_string(),
+                name.clone(),
+                new_name.clone(),
+            ]);
+        }
+        BranchAction::SetUpstream => {
+            let Some(upstream) = &upstream else {
+                return Err(ToolError::ExecutionError(
+                    "upstream is required to set a branch upstream".to_string(),
+                ));
+            };
+            args.extend([
+                "branch".to_string(),
+                format!("--set-upstream-to={}", upstream),
+                name.clone(),
+            ]);
+        }
+        BranchAction::UnsetUpstream => {
+            args.extend([
+                "branch".to_string(),
+                "--unset-upstream".to_string(),
+                name.clone(),
+            ]);
+        }
+    }
+
+    let cmd_args: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+    run_git_command(&repo_path, &cmd_args)?;
+
+    let branch = match action {
+        BranchAction::Rename => new_name.unwrap_or(name),
+        _ => name,
+    };
+
+    let mut result = git_branches(repo_path, false, None, None, None).await?;
+    if let Some(obj) = result.as_object_mut() {
+        obj.insert("success".to_string(), json!(true));
+        obj.insert("action".to_string(), json!(action));
+        obj.insert("branch".to_string(), json!(branch));
+    }
+
+    Ok(result)
+}
@@ -0,0 +1,98 @@
+use crate::tools::sequencer::{SequencerAction, SequencerOptions, run_sequencer};
+use async_trait::async_trait;
+use mcp_core::handler::{ToolError, ToolHandler};
+use schemars::{JsonSchema, schema_for};
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Git cherry-pick tool implementation
+#[derive(Debug, Default)]
+pub struct GitCherryPickTool;
+
+#[derive(Deserialize, JsonSchema)]
+struct GitCherryPickToolParams {
+    #[schemars(description = "The path to the git repository")]
+    repo_path: String,
+    #[schemars(
+        description = "Whether to start cherry-picking (default), or continue, skip or abort the sequence in progress"
+    )]
+    #[serde(default)]
+    action: SequencerAction,
+    #[schemars(description = "The commits or ranges (e.g. 'main~3..main') to apply, oldest first")]
+    #[serde(default)]
+    commits: Vec<String>,
+    #[schemars(
+        description = "The parent number (starting from 1) to diff against when picking a merge commit"
+    )]
+    #[serde(default)]
+    mainline: u32,
+    #[schemars(
+        description = "Whether to apply the changes to the index and working tree without committing"
+    )]
+    #[serde(default)]
+    no_commit: bool,
+    #[schemars(
+        description = "Whether to append '(cherry picked from commit ...)' to each message (-x)"
+    )]
+    #[serde(default)]
+    record_origin: bool,
+}
+
+#[async_trait]
+impl ToolHandler for GitCherryPickTool {
+    fn name(&self) -> &'static str {
+        "git_cherry_pick"
+    }
+
+    fn description(&self) -> &'static str {
+        "Apply the changes of existing commits onto the current branch, or continue, skip or abort a cherry-pick"
+    }
+
+    fn schema(&self) -> Value {
+        serde_json::to_value(schema_for!(GitCherryPickToolParams)).unwrap_or_default()
+    }
+
+    async fn call(&self, params: Value) -> Result<Value, ToolError> {
+        let params: GitCherryPickToolParams =
+            serde_json::from_value(params).map_err(|e| ToolError::ExecutionError(e.to_string()))?;
+
+        let mainline = if params.mainline == 0 {
+            None
+        } else {
+            Some(params.mainline)
+        };
+
+        let options = SequencerOptions {
+            mainline,
+            no_commit: params.no_commit,
+        };
+
+        git_cherry_pick(
+            params.repo_path,
+            params.action,
+            params.commits,
+            options,
+            params.record_origin,
+        )
+        .await
+    }
+}
+
+pub async fn git_cherry_pick(
+    repo_path: String,
+    action: SequencerAction,
+    commits: Vec<String>,
+    options: SequencerOptions,
+    record_origin: bool,
+) -> Result<Value, ToolError> {
+    let extra_args: &[&str] = if record_origin { &["-x"] } else { &[] };
+
+    run_sequencer(
+        &repo_path,
+        "cherry-pick",
+        action,
+        &commits,
+        &options,
+        extra_args,
+    )
+}
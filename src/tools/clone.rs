@@ -0,0 +1,181 @@
+use crate::tools::{read_head, redact_output, redact_url, run_git_command, run_git_command_output};
+use async_trait::async_trait;
+use mcp_core::handler::{ToolError, ToolHandler};
+use schemars::{JsonSchema, schema_for};
+use serde::Deserialize;
+use serde_json::{Value, json};
+use std::path::{Path, PathBuf};
+
+/// Git clone tool implementation
+#[derive(Debug, Default)]
+pub struct GitCloneTool;
+
+#[derive(Deserialize, JsonSchema)]
+struct GitCloneToolParams {
+    #[schemars(description = "The URL or local path of the repository to clone")]
+    url: String,
+    #[schemars(description = "The directory to clone into")]
+    target_dir: String,
+    #[schemars(description = "The branch or tag to check out instead of the remote HEAD")]
+    #[serde(default)]
+    branch: String,
+    #[schemars(description = "Create a shallow clone with this many commits of history")]
+    #[serde(default)]
+    depth: u32,
+    #[schemars(description = "Whether to fetch only the history of the checked-out branch")]
+    #[serde(default)]
+    single_branch: bool,
+    #[schemars(description = "A partial clone filter such as 'blob:none' or 'tree:0'")]
+    #[serde(default)]
+    filter: String,
+    #[schemars(description = "Only check out these directories (sparse checkout in cone mode)")]
+    #[serde(default)]
+    sparse_paths: Vec<String>,
+    #[schemars(description = "Whether to also clone submodules")]
+    #[serde(default)]
+    recurse_submodules: bool,
+}
+
+/// Options for `git clone`
+#[derive(Debug, Default)]
+pub struct CloneOptions {
+    pub branch: Option<String>,
+    pub depth: Option<u32>,
+    pub single_branch: bool,
+    pub filter: Option<String>,
+    pub sparse_paths: Vec<String>,
+    pub recurse_submodules: bool,
+}
+
+#[async_trait]
+impl ToolHandler for GitCloneTool {
+    fn name(&self) -> &'static str {
+        "git_clone"
+    }
+
+    fn description(&self) -> &'static str {
+        "Clone a repository, optionally shallow, partial or sparse"
+    }
+
+    fn schema(&self) -> Value {
+        serde_json::to_value(schema_for!(GitCloneToolParams)).unwrap_or_default()
+    }
+
+    async fn call(&self, params: Value) -> Result<Value, ToolError> {
+        let params: GitCloneToolParams =
+            serde_json::from_value(params).map_err(|e| ToolError::ExecutionError(e.to_string()))?;
+
+        let options = CloneOptions {
+            branch: Some(params.branch).filter(|b| !b.is_empty()),
+            depth: Some(params.depth).filter(|d| *d > 0),
+            single_branch: params.single_branch,
+            filter: Some(params.filter).filter(|f| !f.is_empty()),
+            sparse_paths: params.sparse_paths,
+            recurse_submodules: params.recurse_submodules,
+        };
+
+        git_clone(params.url, params.target_dir, options).await
+    }
+}
+
+pub async fn git_clone(
+    url: String,
+    target_dir: String,
+    options: CloneOptions,
+) -> Result<Value, ToolError> {
+    let (parent, target) = prepare_target(&target_dir)?;
+
+    // Local paths are cloned by hardlinking objects, which ignores --depth and
+    // --filter; a file:// URL goes through the transport that honours them
+    let mut source = url.clone();
+    if let Ok(local) = Path::new(&url).canonicalize() {
+        source = if options.depth.is_some() || options.filter.is_some() {
+            format!("file://{}", local.display())
+        } else {
+            local.to_string_lossy().to_string()
+        };
+    }
+
+    let depth = options.depth.map(|d| d.to_string());
+    let filter = options.filter.as_ref().map(|f| format!("--filter={}", f));
+    let target_str = target.to_string_lossy().to_string();
+
+    let mut args = vec!["clone"];
+
+    if let Some(ref b) = options.branch {
+        args.push("--branch");
+        args.push(b);
+    }
+
+    if let Some(ref d) = depth {
+        args.push("--depth");
+        args.push(d);
+    }
+
+    if options.single_branch {
+        args.push("--single-branch");
+    }
+
+    if let Some(ref f) = filter {
+        args.push(f);
+    }
+
+    if !options.sparse_paths.is_empty() {
+        args.push("--sparse");
+    }
+
+    if options.recurse_submodules {
+        args.push("--recurse-submodules");
+    }
+
+    args.push("--");
+    args.push(&source);
+    args.push(&target_str);
+
+    let output = run_git_command_output(&parent.to_string_lossy(), &args)?;
+    if !output.success {
+        return Err(ToolError::ExecutionError(format!(
+            "Git command failed: {}",
+            redact_output(&output.stderr, &url)
+        )));
+    }
+
+    if !options.sparse_paths.is_empty() {
+        let mut sparse_args = vec!["sparse-checkout", "set", "--"];
+        sparse_args.extend(options.sparse_paths.iter().map(String::as_str));
+        run_git_command(&target_str, &sparse_args)?;
+    }
+
+    Ok(json!({
+        "success": true,
+        "path": target_str,
+        "url": redact_url(&url),
+        "head": read_head(&target_str)?,
+        "shallow": options.depth.is_some(),
+        "filter": options.filter,
+        "sparse_paths": options.sparse_paths,
+        "output": redact_output(format!("{}{}", output.stdout, output.stderr).trim(), &url)
+    }))
+}
+
+/// Resolve the directory a new repository goes into, creating its parent so
+/// git can be run from there
+pub(crate) fn prepare_target(target_dir: &str) -> Result<(PathBuf, PathBuf), ToolError> {
+    if target_dir.is_empty() {
+        return Err(ToolError::ExecutionError(
+            "A target directory is required".to_string(),
+        ));
+    }
+
+    let cwd = std::env::current_dir().map_err(|e| {
+        ToolError::ExecutionError(format!("Failed to read current directory: {}", e))
+    })?;
+    let target = cwd.join(target_dir);
+    let parent = target.parent().map(Path::to_path_buf).unwrap_or(cwd);
+
+    std::fs::create_dir_all(&parent).map_err(|e| {
+        ToolError::ExecutionError(format!("Failed to create '{}': {}", parent.display(), e))
+    })?;
+
+    Ok((parent, target))
+}
@@ -0,0 +1,118 @@
+//! Shared commit record format and parser used by the log tools
+
+use serde::Serialize;
+
+/// Pretty format for `git log`/`git show` that starts every record with an ASCII
+/// record separator followed by the commit oid, and separates fields with NUL,
+/// so no subject or body text can be mistaken for a delimiter. The trailing
+/// NUL ends the fixed fields and leaves room for extra per-commit output such
+/// as `--numstat`.
+pub(crate) const COMMIT_RECORD_FORMAT: &str = "--pretty=format:%x1e%H%x00%h%x00%P%x00%an%x00%ae%x00%aI%x00%at%x00%cn%x00%ce%x00%cI%x00%ct%x00%s%x00%b%x00%(trailers:only,unfold)%x00";
+
+/// Number of NUL-terminated fields in [`COMMIT_RECORD_FORMAT`]
+const FIELD_COUNT: usize = 14;
+
+#[derive(Debug, Serialize)]
+pub(crate) struct Signature {
+    pub name: String,
+    pub email: String,
+    pub date: String,
+    pub timestamp: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct Trailer {
+    pub key: String,
+    pub value: String,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct CommitRecord {
+    pub hash: String,
+    pub short_hash: String,
+    pub parents: Vec<String>,
+    pub author: Signature,
+    pub committer: Signature,
+    pub subject: String,
+    pub body: String,
+    pub trailers: Vec<Trailer>,
+}
+
+/// Parse output produced with [`COMMIT_RECORD_FORMAT`]
+pub(crate) fn parse_commit_records(output: &str) -> Vec<CommitRecord> {
+    split_commit_records(output)
+        .map(|(record, _)| record)
+        .collect()
+}
+
+/// Parse output produced with [`COMMIT_RECORD_FORMAT`], returning each record
+/// together with whatever git printed after it (e.g. `--numstat` lines)
+pub(crate) fn split_commit_records(output: &str) -> impl Iterator<Item = (CommitRecord, &str)> {
+    record_chunks(output).filter_map(|chunk| {
+        let fields: Vec<&str> = chunk.splitn(FIELD_COUNT + 1, '\0').collect();
+        if fields.len() != FIELD_COUNT + 1 {
+            return None;
+        }
+
+        let record = CommitRecord {
+            hash: fields[0].to_string(),
+            short_hash: fields[1].to_string(),
+            parents: fields[2].split_whitespace().map(str::to_string).collect(),
+            author: Signature {
+                name: fields[3].to_string(),
+                email: fields[4].to_string(),
+                date: fields[5].to_string(),
+                timestamp: fields[6].parse().unwrap_or_default(),
+            },
+            committer: Signature {
+                name: fields[7].to_string(),
+                email: fields[8].to_string(),
+                date: fields[9].to_string(),
+                timestamp: fields[10].parse().unwrap_or_default(),
+            },
+            subject: fields[11].to_string(),
+            body: fields[12].trim_end().to_string(),
+            trailers: parse_trailers(fields[13]),
+        };
+
+        Some((record, fields[14]))
+    })
+}
+
+/// Split output into records at each separator that is followed by a commit
+/// oid and a NUL. Messages and paths may themselves contain the separator, so
+/// a separator anywhere else belongs to the record before it.
+fn record_chunks(output: &str) -> impl Iterator<Item = &str> {
+    let mut starts = output
+        .match_indices('\x1e')
+        .map(|(i, _)| i + 1)
+        .filter(|&start| starts_with_oid(&output[start..]))
+        .peekable();
+
+    std::iter::from_fn(move || {
+        let start = starts.next()?;
+        let end = starts.peek().map_or(output.len(), |next| next - 1);
+        Some(&output[start..end])
+    })
+}
+
+/// Whether a record begins with a full SHA-1 or SHA-256 oid and a NUL
+fn starts_with_oid(record: &str) -> bool {
+    record.find('\0').is_some_and(|len| {
+        (len == 40 || len == 64) && record[..len].bytes().all(|b| b.is_ascii_hexdigit())
+    })
+}
+
+/// Parse unfolded `Key: value` trailer lines
+fn parse_trailers(trailers: &str) -> Vec<Trailer> {
+    trailers
+        .lines()
+        .filter_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            Some(Trailer {
+                key: key.trim().to_string(),
+                value: value.trim().to_string(),
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,153 @@
+use crate::tools::status::{StatusEntryKind, operation_in_progress, read_status};
+use crate::tools::{read_toplevel, run_git_command};
+use async_trait::async_trait;
+use mcp_core::handler::{ToolError, ToolHandler};
+use schemars::{JsonSchema, schema_for};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::path::Path;
+
+/// Git conflict resolve tool implementation
+#[derive(Debug, Default)]
+pub struct GitConflictResolveTool;
+
+/// Which version of an unmerged file to keep
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictSide {
+    Ours,
+    Theirs,
+}
+
+/// How to resolve one unmerged file
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ConflictResolution {
+    #[schemars(description = "The unmerged file, relative to the repository root")]
+    pub path: String,
+    #[schemars(description = "Keep our or their version of the file")]
+    #[serde(default)]
+    pub side: Option<ConflictSide>,
+    #[schemars(description = "The resolved file content, used instead of side")]
+    #[serde(default)]
+    pub content: Option<String>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct GitConflictResolveToolParams {
+    #[schemars(description = "The path to the git repository")]
+    repo_path: String,
+    #[schemars(description = "The files to resolve and how to resolve each one")]
+    resolutions: Vec<ConflictResolution>,
+}
+
+#[async_trait]
+impl ToolHandler for GitConflictResolveTool {
+    fn name(&self) -> &'static str {
+        "git_conflict_resolve"
+    }
+
+    fn description(&self) -> &'static str {
+        "Resolve unmerged files with our version, their version or explicit content, and stage the result"
+    }
+
+    fn schema(&self) -> Value {
+        serde_json::to_value(schema_for!(GitConflictResolveToolParams)).unwrap_or_default()
+    }
+
+    async fn call(&self, params: Value) -> Result<Value, ToolError> {
+        let params: GitConflictResolveToolParams =
+            serde_json::from_value(params).map_err(|e| ToolError::ExecutionError(e.to_string()))?;
+
+        git_conflict_resolve(params.repo_path, params.resolutions).await
+    }
+}
+
+pub async fn git_conflict_resolve(
+    repo_path: String,
+    resolutions: Vec<ConflictResolution>,
+) -> Result<Value, ToolError> {
+    // Conflict paths are relative to the top level, so work from there rather
+    // than from a subdirectory
+    let repo_path = read_toplevel(&repo_path)?;
+    let status = read_status(&repo_path, false)?;
+
+    // Check every resolution before touching anything, so a bad entry does not
+    // leave the conflict half resolved
+    let mut planned = Vec::new();
+    for resolution in &resolutions {
+        let Some(entry) = status
+            .entries
+            .iter()
+            .find(|entry| entry.kind == StatusEntryKind::Unmerged && entry.path == resolution.path)
+        else {
+            return Err(ToolError::ExecutionError(format!(
+                "'{}' is not an unmerged path",
+                resolution.path
+            )));
+        };
+
+        match (resolution.side, &resolution.content) {
+            (Some(side), None) => {
+                let side_name = match side {
+                    ConflictSide::Ours => "ours",
+                    ConflictSide::Theirs => "theirs",
+                };
+                let exists = entry.stages.iter().any(|stage| stage.side == side_name);
+                planned.push((resolution, exists));
+            }
+            (None, Some(_)) => planned.push((resolution, true)),
+            _ => {
+                return Err(ToolError::ExecutionError(format!(
+                    "Exactly one of side or content is required for '{}'",
+                    resolution.path
+                )));
+            }
+        }
+    }
+
+    let mut resolved = Vec::new();
+    for (resolution, exists) in planned {
+        let path = resolution.path.as_str();
+
+        let outcome = match (resolution.side, &resolution.content) {
+            // The chosen side deleted the file, so resolve by deleting it
+            (Some(_), _) if !exists => {
+                run_git_command(&repo_path, &["rm", "--quiet", "--force", "--", path])?;
+                "deleted"
+            }
+            (Some(side), _) => {
+                let flag = match side {
+                    ConflictSide::Ours => "--ours",
+                    ConflictSide::Theirs => "--theirs",
+                };
+                run_git_command(&repo_path, &["checkout", flag, "--", path])?;
+                run_git_command(&repo_path, &["add", "--", path])?;
+                "staged"
+            }
+            (None, content) => {
+                std::fs::write(
+                    Path::new(&repo_path).join(path),
+                    content.as_deref().unwrap_or_default(),
+                )
+                .map_err(|e| {
+                    ToolError::ExecutionError(format!("Failed to write '{}': {}", path, e))
+                })?;
+                run_git_command(&repo_path, &["add", "--", path])?;
+                "staged"
+            }
+        };
+
+        resolved.push(json!({
+            "path": path,
+            "side": resolution.side,
+            "result": outcome
+        }));
+    }
+
+    Ok(json!({
+        "success": true,
+        "resolved": resolved,
+        "remaining": read_status(&repo_path, false)?.conflicts(),
+        "in_progress": operation_in_progress(&repo_path)?
+    }))
+}
@@ -0,0 +1,104 @@
+use crate::tools::status::{IndexStage, StatusEntryKind, read_status};
+use crate::tools::{read_toplevel, run_git_command};
+use async_trait::async_trait;
+use mcp_core::handler::{ToolError, ToolHandler};
+use schemars::{JsonSchema, schema_for};
+use serde::Deserialize;
+use serde_json::{Value, json};
+use std::path::Path;
+
+/// Git conflict show tool implementation
+#[derive(Debug, Default)]
+pub struct GitConflictShowTool;
+
+#[derive(Deserialize, JsonSchema)]
+struct GitConflictShowToolParams {
+    #[schemars(description = "The path to the git repository")]
+    repo_path: String,
+    #[schemars(description = "The unmerged file to show, relative to the repository root")]
+    path: String,
+}
+
+#[async_trait]
+impl ToolHandler for GitConflictShowTool {
+    fn name(&self) -> &'static str {
+        "git_conflict_show"
+    }
+
+    fn description(&self) -> &'static str {
+        "Show the base, ours and theirs versions of an unmerged file and its working copy with conflict markers"
+    }
+
+    fn schema(&self) -> Value {
+        serde_json::to_value(schema_for!(GitConflictShowToolParams)).unwrap_or_default()
+    }
+
+    async fn call(&self, params: Value) -> Result<Value, ToolError> {
+        let params: GitConflictShowToolParams =
+            serde_json::from_value(params).map_err(|e| ToolError::ExecutionError(e.to_string()))?;
+
+        git_conflict_show(params.repo_path, params.path).await
+    }
+}
+
+pub async fn git_conflict_show(repo_path: String, path: String) -> Result<Value, ToolError> {
+    let status = read_status(&repo_path, false)?;
+    let Some(entry) = status
+        .entries
+        .iter()
+        .find(|entry| entry.kind == StatusEntryKind::Unmerged && entry.path == path)
+    else {
+        return Err(ToolError::ExecutionError(format!(
+            "'{}' is not an unmerged path",
+            path
+        )));
+    };
+
+    let stage = |side: &str| -> Result<Value, ToolError> {
+        match entry.stages.iter().find(|stage| stage.side == side) {
+            Some(stage) => stage_version(&repo_path, stage),
+            None => Ok(Value::Null),
+        }
+    };
+
+    // The working copy is missing when the conflict left the file deleted
+    let toplevel = read_toplevel(&repo_path)?;
+    let working = match std::fs::read(Path::new(&toplevel).join(&path)) {
+        Ok(bytes) => {
+            let content = String::from_utf8_lossy(&bytes);
+            let has_markers = content.lines().any(|line| line.starts_with("<<<<<<<"))
+                && content.lines().any(|line| line.starts_with(">>>>>>>"));
+            file_content(&content, json!({ "has_markers": has_markers }))
+        }
+        Err(_) => Value::Null,
+    };
+
+    Ok(json!({
+        "path": entry.path,
+        "conflict": entry.conflict,
+        "base": stage("base")?,
+        "ours": stage("ours")?,
+        "theirs": stage("theirs")?,
+        "working": working
+    }))
+}
+
+/// Read the blob recorded in one index stage
+fn stage_version(repo_path: &str, stage: &IndexStage) -> Result<Value, ToolError> {
+    let content = run_git_command(repo_path, &["cat-file", "blob", &stage.oid])?;
+
+    Ok(file_content(
+        &content,
+        json!({ "stage": stage.stage, "mode": stage.mode, "oid": stage.oid }),
+    ))
+}
+
+/// Add content to `fields`, leaving it out for binary files
+fn file_content(content: &str, mut fields: Value) -> Value {
+    let binary = content.contains('\0');
+
+    fields["binary"] = json!(binary);
+    fields["content"] = if binary { Value::Null } else { json!(content) };
+
+    fields
+}
@@ -0,0 +1,47 @@
+use crate::tools::status::{operation_in_progress, read_status};
+use async_trait::async_trait;
+use mcp_core::handler::{ToolError, ToolHandler};
+use schemars::{JsonSchema, schema_for};
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+/// Git conflicts list tool implementation
+#[derive(Debug, Default)]
+pub struct GitConflictsListTool;
+
+#[derive(Deserialize, JsonSchema)]
+struct GitConflictsListToolParams {
+    #[schemars(description = "The path to the git repository")]
+    repo_path: String,
+}
+
+#[async_trait]
+impl ToolHandler for GitConflictsListTool {
+    fn name(&self) -> &'static str {
+        "git_conflicts_list"
+    }
+
+    fn description(&self) -> &'static str {
+        "List unmerged paths with their conflict type and index stages"
+    }
+
+    fn schema(&self) -> Value {
+        serde_json::to_value(schema_for!(GitConflictsListToolParams)).unwrap_or_default()
+    }
+
+    async fn call(&self, params: Value) -> Result<Value, ToolError> {
+        let params: GitConflictsListToolParams =
+            serde_json::from_value(params).map_err(|e| ToolError::ExecutionError(e.to_string()))?;
+
+        git_conflicts_list(params.repo_path).await
+    }
+}
+
+pub async fn git_conflicts_list(repo_path: String) -> Result<Value, ToolError> {
+    let conflicts = read_status(&repo_path, false)?.conflicts();
+
+    Ok(json!({
+        "in_progress": operation_in_progress(&repo_path)?,
+        "conflicts": conflicts
+    }))
+}
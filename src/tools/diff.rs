@@ -1,5 +1,6 @@
-use crate::tools::run_git_command;
+use crate::tools::{run_git_command, unquote_path};
 use mcp_core::handler::{ToolError, ToolHandler};
+use serde::Serialize;
 use serde_json::{Value, json};
 
 /// Git diff tool implementation
@@ -18,6 +19,11 @@ struct GitDiffToolParams {
     #[schemars(description = "The commit to diff against")]
     #[serde(default)]
     commit: String,
+    #[schemars(
+        description = "Whether to return the diff as structured files and hunks instead of a single string"
+    )]
+    #[serde(default)]
+    structured: bool,
 }
 
 #[async_trait::async_trait]
@@ -57,7 +63,7 @@ impl ToolHandler for GitDiffTool {
             Some(params.path)
         };  
 
-        git_diff(params.repo_path, path, staged, commit).await
+        git_diff(params.repo_path, path, staged, commit, params.structured).await
     }
 }
 
@@ -66,6 +72,7 @@ pub async fn git_diff(
     path: Option<String>,
     staged: Option<bool>,
     commit: Option<String>,
+    structured: bool,
 ) -> Result<Value, ToolError> {
     // Create basic command arguments
     let base_args = vec!["diff"];
@@ -74,6 +81,11 @@ pub async fn git_diff(
     let mut cmd_args = Vec::new();
     cmd_args.extend_from_slice(&base_args);
 
+    // Structured output needs a stable, uncolored patch with rename and copy detection
+    if structured {
+        cmd_args.extend_from_slice(&["--no-color", "--no-ext-diff", "-M", "-C"]);
+    }
+
     // Handle staged option
     if staged.unwrap_or(false) {
         cmd_args.push("--staged");
@@ -92,7 +104,434 @@ pub async fn git_diff(
 
     let diff_output = run_git_command(&repo_path, &cmd_args)?;
 
+    if structured {
+        return Ok(json!({
+            "files": parse_unified_diff(&diff_output)
+        }));
+    }
+
     Ok(json!({
         "changes": diff_output
     }))
 }
+
+/// The kind of change a diff applies to a single file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum DiffChangeType {
+    Added,
+    Deleted,
+    Modified,
+    Renamed,
+    Copied,
+    ModeChange,
+    Unmerged,
+}
+
+impl DiffChangeType {
+    /// Map a `--raw`/`--name-status` status letter, using the modes to tell a
+    /// pure mode change apart from a content change
+    pub(crate) fn from_status(status: &str, old_mode: &str, new_mode: &str) -> Self {
+        match status.chars().next() {
+            Some('A') => DiffChangeType::Added,
+            Some('D') => DiffChangeType::Deleted,
+            Some('R') => DiffChangeType::Renamed,
+            Some('C') => DiffChangeType::Copied,
+            _ if old_mode != new_mode => DiffChangeType::ModeChange,
+            _ => DiffChangeType::Modified,
+        }
+    }
+}
+
+/// The role of a single line inside a hunk
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum DiffLineType {
+    Context,
+    Add,
+    Remove,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct DiffLine {
+    #[serde(rename = "type")]
+    pub line_type: DiffLineType,
+    pub content: String,
+    pub old_line: Option<u32>,
+    pub new_line: Option<u32>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub no_newline_at_eof: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct DiffHunk {
+    pub header: String,
+    pub old_start: u32,
+    pub old_lines: u32,
+    pub new_start: u32,
+    pub new_lines: u32,
+    pub section: Option<String>,
+    pub lines: Vec<DiffLine>,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct DiffFile {
+    pub old_path: Option<String>,
+    pub new_path: Option<String>,
+    pub change_type: DiffChangeType,
+    pub old_mode: Option<String>,
+    pub new_mode: Option<String>,
+    pub similarity: Option<u32>,
+    pub binary: bool,
+    pub hunks: Vec<DiffHunk>,
+}
+
+impl DiffFile {
+    fn new(header: &str) -> Self {
+        // The `diff --git a/<old> b/<new>` header is ambiguous when paths contain
+        // spaces, so it is only trusted when both halves name the same file.
+        // Renames, copies and content changes overwrite these from later lines.
+        let path = header_path(header);
+
+        DiffFile {
+            old_path: path.clone(),
+            new_path: path,
+            change_type: DiffChangeType::Modified,
+            old_mode: None,
+            new_mode: None,
+            similarity: None,
+            binary: false,
+            hunks: Vec::new(),
+        }
+    }
+
+    /// A path with unresolved conflicts, shown by git as a combined diff
+    /// against every parent. Its hunks have one column per parent and are not
+    /// broken down; git_conflict_show describes the conflict instead.
+    fn unmerged(path: &str) -> Self {
+        DiffFile {
+            old_path: Some(path.to_string()),
+            new_path: Some(path.to_string()),
+            change_type: DiffChangeType::Unmerged,
+            old_mode: None,
+            new_mode: None,
+            similarity: None,
+            binary: false,
+            hunks: Vec::new(),
+        }
+    }
+
+    fn finish(mut self) -> Self {
+        if self.change_type == DiffChangeType::Modified
+            && self.hunks.is_empty()
+            && !self.binary
+            && self.old_mode.is_some()
+            && self.old_mode != self.new_mode
+        {
+            self.change_type = DiffChangeType::ModeChange;
+        }
+        self
+    }
+}
+
+/// Parse `git diff` patch output into files, hunks and typed lines
+pub(crate) fn parse_unified_diff(output: &str) -> Vec<DiffFile> {
+    let mut files = Vec::new();
+    let mut current: Option<DiffFile> = None;
+    let mut old_line = 0;
+    let mut new_line = 0;
+
+    // Split on '\n' only, so the '\r' of CRLF files stays part of the content
+    for line in output.split('\n') {
+        if let Some(rest) = line.strip_prefix("diff --git ") {
+            if let Some(file) = current.take() {
+                files.push(file.finish());
+            }
+            current = Some(DiffFile::new(rest));
+            continue;
+        }
+
+        if let Some(path) = line
+            .strip_prefix("diff --cc ")
+            .or_else(|| line.strip_prefix("diff --combined "))
+        {
+            if let Some(file) = current.take() {
+                files.push(file.finish());
+            }
+            // Everything up to the next file header belongs to the combined diff
+            files.push(DiffFile::unmerged(&unquote_path(path)));
+            continue;
+        }
+
+        let Some(file) = current.as_mut() else {
+            continue;
+        };
+
+        // Inside a hunk every line starts with a marker character
+        if let Some(hunk) = file.hunks.last_mut() {
+            let remaining = (hunk.old_start + hunk.old_lines).saturating_sub(old_line)
+                + (hunk.new_start + hunk.new_lines).saturating_sub(new_line);
+
+            if let Some(rest) = line.strip_prefix('\\') {
+                if rest.trim() == "No newline at end of file"
+                    && let Some(last) = hunk.lines.last_mut()
+                {
+                    last.no_newline_at_eof = true;
+                }
+                continue;
+            }
+
+            if remaining > 0 && !line.starts_with("@@ ") {
+                let (line_type, content) = match line.split_at_checked(1) {
+                    Some(("+", content)) => (DiffLineType::Add, content),
+                    Some(("-", content)) => (DiffLineType::Remove, content),
+                    Some((_, content)) => (DiffLineType::Context, content),
+                    None => (DiffLineType::Context, ""),
+                };
+
+                let (old, new) = match line_type {
+                    DiffLineType::Add => {
+                        new_line += 1;
+                        (None, Some(new_line - 1))
+                    }
+                    DiffLineType::Remove => {
+                        old_line += 1;
+                        (Some(old_line - 1), None)
+                    }
+                    DiffLineType::Context => {
+                        old_line += 1;
+                        new_line += 1;
+                        (Some(old_line - 1), Some(new_line - 1))
+                    }
+                };
+
+                hunk.lines.push(DiffLine {
+                    line_type,
+                    content: content.to_string(),
+                    old_line: old,
+                    new_line: new,
+                    no_newline_at_eof: false,
+                });
+                continue;
+            }
+        }
+
+        if line.starts_with("@@ ") {
+            if let Some(hunk) = parse_hunk_header(line) {
+                old_line = hunk.old_start;
+                new_line = hunk.new_start;
+                file.hunks.push(hunk);
+            }
+        } else if let Some(mode) = line.strip_prefix("new file mode ") {
+            file.change_type = DiffChangeType::Added;
+            file.old_path = None;
+            file.new_mode = Some(mode.to_string());
+        } else if let Some(mode) = line.strip_prefix("deleted file mode ") {
+            file.change_type = DiffChangeType::Deleted;
+            file.new_path = None;
+            file.old_mode = Some(mode.to_string());
+        } else if let Some(mode) = line.strip_prefix("old mode ") {
+            file.old_mode = Some(mode.to_string());
+        } else if let Some(mode) = line.strip_prefix("new mode ") {
+            file.new_mode = Some(mode.to_string());
+        } else if let Some(path) = line.strip_prefix("rename from ") {
+            file.change_type = DiffChangeType::Renamed;
+            file.old_path = Some(unquote_path(path));
+        } else if let Some(path) = line.strip_prefix("rename to ") {
+            file.change_type = DiffChangeType::Renamed;
+            file.new_path = Some(unquote_path(path));
+        } else if let Some(path) = line.strip_prefix("copy from ") {
+            file.change_type = DiffChangeType::Copied;
+            file.old_path = Some(unquote_path(path));
+        } else if let Some(path) = line.strip_prefix("copy to ") {
+            file.change_type = DiffChangeType::Copied;
+            file.new_path = Some(unquote_path(path));
+        } else if let Some(score) = line.strip_prefix("similarity index ") {
+            file.similarity = score.trim_end_matches('%').parse().ok();
+        } else if let Some(index) = line.strip_prefix("index ") {
+            // `index <old>..<new> <mode>` carries the mode when it did not change
+            if let Some((_, mode)) = index.split_once(' ') {
+                file.old_mode.get_or_insert_with(|| mode.to_string());
+                file.new_mode.get_or_insert_with(|| mode.to_string());
+            }
+        } else if line.starts_with("Binary files ") || line == "GIT binary patch" {
+            file.binary = true;
+        } else if let Some(path) = line.strip_prefix("--- ") {
+            if file.change_type != DiffChangeType::Added {
+                file.old_path = strip_diff_prefix(path, "a/");
+            }
+        } else if let Some(path) = line.strip_prefix("+++ ")
+            && file.change_type != DiffChangeType::Deleted
+        {
+            file.new_path = strip_diff_prefix(path, "b/");
+        }
+    }
+
+    if let Some(file) = current.take() {
+        files.push(file.finish());
+    }
+
+    files
+}
+
+/// Parse a `@@ -a,b +c,d @@ section` hunk header
+fn parse_hunk_header(line: &str) -> Option<DiffHunk> {
+    let rest = line.strip_prefix("@@ ")?;
+    let (ranges, section) = rest.split_once(" @@")?;
+    let (old, new) = ranges.split_once(' ')?;
+    let (old_start, old_lines) = parse_hunk_range(old.strip_prefix('-')?)?;
+    let (new_start, new_lines) = parse_hunk_range(new.strip_prefix('+')?)?;
+    let section = section.trim();
+
+    Some(DiffHunk {
+        header: line.to_string(),
+        old_start,
+        old_lines,
+        new_start,
+        new_lines,
+        section: if section.is_empty() {
+            None
+        } else {
+            Some(section.to_string())
+        },
+        lines: Vec::new(),
+    })
+}
+
+/// Parse a `start[,count]` hunk range, where a missing count means one line
+fn parse_hunk_range(range: &str) -> Option<(u32, u32)> {
+    match range.split_once(',') {
+        Some((start, count)) => Some((start.parse().ok()?, count.parse().ok()?)),
+        None => Some((range.parse().ok()?, 1)),
+    }
+}
+
+/// Resolve a `---`/`+++` path, mapping `/dev/null` to no path
+fn strip_diff_prefix(path: &str, prefix: &str) -> Option<String> {
+    let path = unquote_path(path.trim_end_matches('\t'));
+    if path == "/dev/null" {
+        return None;
+    }
+    Some(
+        path.strip_prefix(prefix)
+            .map(str::to_string)
+            .unwrap_or(path),
+    )
+}
+
+/// Extract the path from `a/<path> b/<path>` when both sides are identical
+fn header_path(header: &str) -> Option<String> {
+    if header.starts_with('"') {
+        let (old, new) = split_quoted_pair(header)?;
+        return (old.strip_prefix("a/")? == new.strip_prefix("b/")?).then(|| old[2..].to_string());
+    }
+
+    // Unquoted: "a/" + P + " b/" + P, so the length is 2 * len(P) + 5
+    let len = header.len();
+    if len < 5 || !(len - 5).is_multiple_of(2) {
+        return None;
+    }
+    let path_len = (len - 5) / 2;
+    let old = header.get(2..2 + path_len)?;
+    let new = header.get(path_len + 5..)?;
+    (header.starts_with("a/") && header.get(2 + path_len..path_len + 5)? == " b/" && old == new)
+        .then(|| old.to_string())
+}
+
+/// Split a header of two C-quoted paths into their unquoted forms
+fn split_quoted_pair(header: &str) -> Option<(String, String)> {
+    let mut escaped = false;
+    for (i, c) in header.char_indices().skip(1) {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' => {
+                let (old, new) = header.split_at(i + 1);
+                return Some((unquote_path(old), unquote_path(new.trim_start())));
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Per-file change summary from `--raw --numstat`
+#[derive(Debug, Serialize)]
+pub(crate) struct FileStat {
+    pub path: String,
+    pub old_path: Option<String>,
+    pub change_type: DiffChangeType,
+    pub additions: Option<u64>,
+    pub deletions: Option<u64>,
+    pub binary: bool,
+}
+
+/// Summarise the files changed between two trees, e.g. `&["old", "new"]`
+pub(crate) fn diff_stats(repo_path: &str, range: &[&str]) -> Result<Vec<FileStat>, ToolError> {
+    let mut args = vec!["diff", "--raw", "--numstat", "-z", "-M", "--no-abbrev"];
+    args.extend_from_slice(range);
+
+    let diff_output = run_git_command(repo_path, &args)?;
+
+    Ok(parse_raw_numstat(&diff_output))
+}
+
+/// Parse NUL-delimited `--raw --numstat -z` output, pairing the numstat
+/// records with the raw records in the order git prints them
+pub(crate) fn parse_raw_numstat(output: &str) -> Vec<FileStat> {
+    let mut files = Vec::new();
+    let mut numstat_index = 0;
+    let mut tokens = output.split('\0');
+
+    while let Some(token) = tokens.next() {
+        let token = token.trim_start_matches('\n');
+        if token.is_empty() {
+            continue;
+        }
+
+        if let Some(raw) = token.strip_prefix(':') {
+            // :<old mode> <new mode> <old oid> <new oid> <status>, then one or two paths
+            let meta: Vec<&str> = raw.split_whitespace().collect();
+            if meta.len() != 5 {
+                continue;
+            }
+
+            let change_type = DiffChangeType::from_status(meta[4], meta[0], meta[1]);
+            let first = tokens.next().unwrap_or_default().to_string();
+            let (path, old_path) = match change_type {
+                DiffChangeType::Renamed | DiffChangeType::Copied => {
+                    (tokens.next().unwrap_or_default().to_string(), Some(first))
+                }
+                _ => (first, None),
+            };
+
+            files.push(FileStat {
+                path,
+                old_path,
+                change_type,
+                additions: None,
+                deletions: None,
+                binary: false,
+            });
+        } else {
+            // <added>\t<deleted>\t<path>, or an empty path followed by two path tokens
+            let fields: Vec<&str> = token.splitn(3, '\t').collect();
+            if fields.len() != 3 {
+                continue;
+            }
+            if fields[2].is_empty() {
+                tokens.next();
+                tokens.next();
+            }
+
+            if let Some(file) = files.get_mut(numstat_index) {
+                file.additions = fields[0].parse().ok();
+                file.deletions = fields[1].parse().ok();
+                file.binary = fields[0] == "-";
+            }
+            numstat_index += 1;
+        }
+    }
+
+    files
+}
@@ -0,0 +1,363 @@
+use crate::tools::{resolve_rev, run_git_command_output};
+use async_trait::async_trait;
+use mcp_core::handler::{ToolError, ToolHandler};
+use schemars::{JsonSchema, schema_for};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+/// Git fetch tool implementation
+#[derive(Debug, Default)]
+pub struct GitFetchTool;
+
+#[derive(Deserialize, JsonSchema)]
+struct GitFetchToolParams {
+    #[schemars(description = "The path to the git repository")]
+    repo_path: String,
+    #[schemars(description = "The remote to fetch from")]
+    #[serde(default)]
+    remote: String,
+    #[schemars(
+        description = "The refspecs to fetch, e.g. 'main' or '+refs/heads/*:refs/remotes/origin/*'"
+    )]
+    #[serde(default)]
+    refspecs: Vec<String>,
+    #[schemars(
+        description = "Whether to remove remote-tracking refs that no longer exist on the remote"
+    )]
+    #[serde(default)]
+    prune: bool,
+    #[schemars(description = "Whether to fetch all tags")]
+    #[serde(default)]
+    tags: bool,
+    #[schemars(description = "Limit fetching to this many commits from each branch tip")]
+    #[serde(default)]
+    depth: u32,
+}
+
+/// How a single ref was updated by the fetch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum RefUpdateStatus {
+    New,
+    FastForward,
+    Forced,
+    Pruned,
+    TagUpdate,
+    Rejected,
+    UpToDate,
+}
+
+#[derive(Debug, Serialize)]
+struct RefUpdate {
+    status: RefUpdateStatus,
+    old_oid: Option<String>,
+    new_oid: Option<String>,
+    local_ref: String,
+    reason: Option<String>,
+}
+
+#[async_trait]
+impl ToolHandler for GitFetchTool {
+    fn name(&self) -> &'static str {
+        "git_fetch"
+    }
+
+    fn description(&self) -> &'static str {
+        "Download objects and refs from a remote without integrating them"
+    }
+
+    fn schema(&self) -> Value {
+        serde_json::to_value(schema_for!(GitFetchToolParams)).unwrap_or_default()
+    }
+
+    async fn call(&self, params: Value) -> Result<Value, ToolError> {
+        let params: GitFetchToolParams =
+            serde_json::from_value(params).map_err(|e| ToolError::ExecutionError(e.to_string()))?;
+
+        let remote = if params.remote.is_empty() {
+            None
+        } else {
+            Some(params.remote)
+        };
+
+        let depth = if params.depth == 0 {
+            None
+        } else {
+            Some(params.depth)
+        };
+
+        git_fetch(
+            params.repo_path,
+            remote,
+            params.refspecs,
+            params.prune,
+            params.tags,
+            depth,
+        )
+        .await
+    }
+}
+
+pub async fn git_fetch(
+    repo_path: String,
+    remote: Option<String>,
+    refspecs: Vec<String>,
+    prune: bool,
+    tags: bool,
+    depth: Option<u32>,
+) -> Result<Value, ToolError> {
+    let remote_name = remote.unwrap_or_else(|| "origin".to_string());
+
+    // `--porcelain` only exists since git 2.41; older versions are parsed from
+    // the human-readable summary, which carries the same flags
+    let porcelain = supports_porcelain(&repo_path)?;
+
+    let mut args = vec!["fetch".to_string()];
+
+    // The summary leaves out refs that are already up to date unless verbose
+    if porcelain {
+        args.push("--porcelain".to_string());
+    } else {
+        args.push("-v".to_string());
+    }
+
+    if prune {
+        args.push("--prune".to_string());
+    }
+
+    if tags {
+        args.push("--tags".to_string());
+    }
+
+    if let Some(depth) = depth {
+        args.push(format!("--depth={}", depth));
+    }
+
+    args.push(remote_name.clone());
+    args.extend(refspecs.iter().cloned());
+
+    let cmd_args: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+    let output = run_git_command_output(&repo_path, &cmd_args)?;
+
+    let updates: Vec<RefUpdate> = if porcelain {
+        output
+            .stdout
+            .lines()
+            .filter_map(parse_porcelain_line)
+            .collect()
+    } else {
+        let destinations = prune_destinations(&repo_path, &remote_name, &refspecs)?;
+        output
+            .stderr
+            .lines()
+            .filter_map(parse_summary_line)
+            .map(|update| complete_summary_update(&repo_path, &destinations, update))
+            .collect::<Result<_, _>>()?
+    };
+
+    // Rejected refs make git exit non-zero, but are reported as data
+    let rejected = updates
+        .iter()
+        .any(|update| update.status == RefUpdateStatus::Rejected);
+    if !output.success && !rejected {
+        return Err(ToolError::ExecutionError(format!(
+            "Git command failed: {}",
+            output.stderr
+        )));
+    }
+
+    Ok(json!({
+        "success": output.success,
+        "remote": remote_name,
+        "updates": updates
+    }))
+}
+
+fn supports_porcelain(repo_path: &str) -> Result<bool, ToolError> {
+    let version = run_git_command_output(repo_path, &["--version"])?;
+
+    // "git version 2.41.0" or e.g. "git version 2.39.5 (Apple Git-154)"
+    let mut numbers = version
+        .stdout
+        .split_whitespace()
+        .nth(2)
+        .unwrap_or_default()
+        .split('.')
+        .map(|n| n.parse::<u32>().unwrap_or(0));
+    let major = numbers.next().unwrap_or(0);
+    let minor = numbers.next().unwrap_or(0);
+
+    Ok((major, minor) >= (2, 41))
+}
+
+fn status_from_flag(flag: char) -> Option<RefUpdateStatus> {
+    match flag {
+        ' ' => Some(RefUpdateStatus::FastForward),
+        '+' => Some(RefUpdateStatus::Forced),
+        '-' => Some(RefUpdateStatus::Pruned),
+        't' => Some(RefUpdateStatus::TagUpdate),
+        '*' => Some(RefUpdateStatus::New),
+        '!' => Some(RefUpdateStatus::Rejected),
+        '=' => Some(RefUpdateStatus::UpToDate),
+        _ => None,
+    }
+}
+
+/// Parse `<flag> <old-oid> <new-oid> <local-ref>`
+fn parse_porcelain_line(line: &str) -> Option<RefUpdate> {
+    let mut chars = line.chars();
+    let status = status_from_flag(chars.next()?)?;
+    let fields: Vec<&str> = chars.as_str().trim_start().splitn(3, ' ').collect();
+    let [old_oid, new_oid, local_ref] = fields.as_slice() else {
+        return None;
+    };
+
+    let oid = |oid: &str| Some(oid.to_string()).filter(|o| !o.bytes().all(|b| b == b'0'));
+
+    Some(RefUpdate {
+        status,
+        old_oid: oid(old_oid),
+        new_oid: oid(new_oid),
+        local_ref: local_ref.to_string(),
+        reason: None,
+    })
+}
+
+/// Parse ` <flag> <summary> <from> -> <to> [(<reason>)]`
+fn parse_summary_line(line: &str) -> Option<RefUpdate> {
+    let rest = line.strip_prefix(' ')?;
+    let mut chars = rest.chars();
+    let status = status_from_flag(chars.next()?)?;
+    let rest = chars.as_str().strip_prefix(' ')?.trim_start();
+
+    // The summary is either a bracketed word such as "[new branch]" or an "old..new" range
+    let (summary, rest) = if rest.starts_with('[') {
+        let end = rest.find(']')?;
+        (&rest[..=end], &rest[end + 1..])
+    } else {
+        rest.split_once(' ')?
+    };
+
+    // The remote ref is dropped, as `--porcelain` does not report it
+    let (_, to) = rest.trim().split_once(" -> ")?;
+    let (to, reason) = match to.split_once("  (") {
+        Some((to, reason)) => (to, Some(reason.trim_end_matches(')').to_string())),
+        None => (to, None),
+    };
+
+    let (old_oid, new_oid) = match summary.split_once("..") {
+        Some((old, new)) => (
+            Some(old.to_string()),
+            Some(new.trim_start_matches('.').to_string()),
+        ),
+        None => (None, None),
+    };
+
+    Some(RefUpdate {
+        status,
+        old_oid,
+        new_oid,
+        local_ref: to.trim().to_string(),
+        reason,
+    })
+}
+
+/// The local refs a fetch prunes: the destinations of the refspecs given, or
+/// else of the remote's configured ones (a URL instead of a remote has none)
+fn prune_destinations(
+    repo_path: &str,
+    remote: &str,
+    refspecs: &[String],
+) -> Result<Vec<String>, ToolError> {
+    let specs: Vec<String> = if refspecs.is_empty() {
+        let configured = run_git_command_output(
+            repo_path,
+            &["config", "--get-all", &format!("remote.{}.fetch", remote)],
+        )?;
+        configured.stdout.lines().map(str::to_string).collect()
+    } else {
+        refspecs.to_vec()
+    };
+
+    // Negative refspecs start with '^', and a refspec without ':' stores nothing
+    Ok(specs
+        .iter()
+        .filter(|spec| !spec.starts_with('^'))
+        .filter_map(|spec| spec.split_once(':'))
+        .map(|(_, dst)| dst.to_string())
+        .filter(|dst| !dst.is_empty())
+        .collect())
+}
+
+/// Whether a full ref name matches a refspec destination, which may contain
+/// one '*' wildcard
+fn matches_destination(name: &str, destination: &str) -> bool {
+    match destination.split_once('*') {
+        Some((prefix, suffix)) => {
+            name.len() >= prefix.len() + suffix.len()
+                && name.starts_with(prefix)
+                && name.ends_with(suffix)
+        }
+        None => name == destination,
+    }
+}
+
+/// Give an update parsed from the summary the shape `--porcelain` reports:
+/// a full local ref name and full oids instead of abbreviated ones
+fn complete_summary_update(
+    repo_path: &str,
+    destinations: &[String],
+    mut update: RefUpdate,
+) -> Result<RefUpdate, ToolError> {
+    let full_name = run_git_command_output(
+        repo_path,
+        &[
+            "rev-parse",
+            "--symbolic-full-name",
+            "--verify",
+            "--quiet",
+            &update.local_ref,
+        ],
+    )?;
+
+    update.local_ref = match full_name.stdout.trim() {
+        name if full_name.success && name.starts_with("refs/") => name.to_string(),
+        // Pruned refs no longer exist; git shortened their name the way it
+        // shortens any ref, so find the expansion a fetch refspec writes to
+        _ => ["refs/", "refs/tags/", "refs/heads/", "refs/remotes/"]
+            .iter()
+            .map(|prefix| format!("{}{}", prefix, update.local_ref))
+            .find(|name| {
+                destinations
+                    .iter()
+                    .any(|destination| matches_destination(name, destination))
+            })
+            .unwrap_or(update.local_ref),
+    };
+
+    update.old_oid = match update.old_oid {
+        Some(ref oid) => resolve_rev(repo_path, &format!("{}^{{object}}", oid))?,
+        None if update.status == RefUpdateStatus::Rejected => {
+            resolve_rev(repo_path, &update.local_ref)?
+        }
+        None => None,
+    };
+
+    update.new_oid = match update.new_oid {
+        Some(ref oid) => resolve_rev(repo_path, &format!("{}^{{object}}", oid))?,
+        None if matches!(
+            update.status,
+            RefUpdateStatus::Pruned | RefUpdateStatus::Rejected
+        ) =>
+        {
+            None
+        }
+        None => resolve_rev(repo_path, &update.local_ref)?,
+    };
+
+    if update.status == RefUpdateStatus::UpToDate {
+        update.old_oid = update.new_oid.clone();
+    }
+
+    Ok(update)
+}
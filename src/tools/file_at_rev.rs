@@ -0,0 +1,294 @@
+use crate::tools::{run_git_command, run_git_command_prefix};
+use async_trait::async_trait;
+use mcp_core::handler::{ToolError, ToolHandler};
+use schemars::{JsonSchema, schema_for};
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+/// Git file at revision tool implementation
+#[derive(Debug, Default)]
+pub struct GitFileAtRevTool;
+
+#[derive(Deserialize, JsonSchema)]
+struct GitFileAtRevToolParams {
+    #[schemars(description = "The path to the git repository")]
+    repo_path: String,
+    #[schemars(description = "The file to read, relative to the repository root")]
+    path: String,
+    #[schemars(description = "The revision to read the file at (defaults to HEAD)")]
+    #[serde(default)]
+    rev: String,
+    #[schemars(
+        description = "The byte offset to start reading at; byte ranges are returned as base64"
+    )]
+    #[serde(default)]
+    start_byte: u64,
+    #[schemars(description = "The maximum number of bytes to return")]
+    #[serde(default)]
+    max_bytes: u64,
+    #[schemars(description = "The first line to return (1-based)")]
+    #[serde(default)]
+    start_line: u32,
+    #[schemars(description = "The last line to return (inclusive)")]
+    #[serde(default)]
+    end_line: u32,
+}
+
+/// Which part of the file to return
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileRange {
+    Whole,
+    Bytes { start: u64, max: Option<u64> },
+    Lines { start: u32, end: Option<u32> },
+}
+
+#[async_trait]
+impl ToolHandler for GitFileAtRevTool {
+    fn name(&self) -> &'static str {
+        "git_file_at_rev"
+    }
+
+    fn description(&self) -> &'static str {
+        "Read a file as it exists at a revision, optionally limited to a byte or line range"
+    }
+
+    fn schema(&self) -> Value {
+        serde_json::to_value(schema_for!(GitFileAtRevToolParams)).unwrap_or_default()
+    }
+
+    async fn call(&self, params: Value) -> Result<Value, ToolError> {
+        let params: GitFileAtRevToolParams =
+            serde_json::from_value(params).map_err(|e| ToolError::ExecutionError(e.to_string()))?;
+
+        let rev = if params.rev.is_empty() {
+            None
+        } else {
+            Some(params.rev)
+        };
+
+        let by_bytes = params.start_byte > 0 || params.max_bytes > 0;
+        let by_lines = params.start_line > 0 || params.end_line > 0;
+
+        let range = match (by_bytes, by_lines) {
+            (true, true) => {
+                return Err(ToolError::ExecutionError(
+                    "Use either a byte range or a line range, not both".to_string(),
+                ));
+            }
+            (true, false) => FileRange::Bytes {
+                start: params.start_byte,
+                max: Some(params.max_bytes).filter(|max| *max > 0),
+            },
+            (false, true) => FileRange::Lines {
+                start: params.start_line.max(1),
+                end: Some(params.end_line).filter(|end| *end > 0),
+            },
+            (false, false) => FileRange::Whole,
+        };
+
+        git_file_at_rev(params.repo_path, params.path, rev, range).await
+    }
+}
+
+pub async fn git_file_at_rev(
+    repo_path: String,
+    path: String,
+    rev: Option<String>,
+    range: FileRange,
+) -> Result<Value, ToolError> {
+    let rev = rev.unwrap_or_else(|| "HEAD".to_string());
+    let object = format!("{}:{}", rev, path.trim_start_matches('/'));
+
+    let oid = run_git_command(
+        &repo_path,
+        &["rev-parse", "--verify", "--end-of-options", &object],
+    )?;
+    let oid = oid.trim();
+
+    let object_type = run_git_command(&repo_path, &["cat-file", "-t", oid])?;
+    if object_type.trim() != "blob" {
+        return Err(ToolError::ExecutionError(format!(
+            "'{}' is a {}, not a file",
+            path,
+            object_type.trim()
+        )));
+    }
+
+    let size: u64 = run_git_command(&repo_path, &["cat-file", "-s", oid])?
+        .trim()
+        .parse()
+        .map_err(|_| ToolError::ExecutionError(format!("Failed to read the size of '{}'", path)))?;
+
+    // Stream the blob and stop once the range and the bytes the encoding is
+    // detected from have been read, so only whole-file reads hold all of it
+    let mut newlines = 0;
+    let mut scanned = 0;
+    let bytes = run_git_command_prefix(&repo_path, &["cat-file", "blob", oid], |read| {
+        if read.len() < DETECTION_BYTES {
+            return false;
+        }
+
+        match range {
+            FileRange::Whole => false,
+            FileRange::Bytes { start, max } => {
+                max.is_some_and(|max| read.len() as u64 >= start.saturating_add(max))
+            }
+            // UTF-16 lines cannot be counted by their newline bytes
+            FileRange::Lines { end, .. } => {
+                newlines += read[scanned..].iter().filter(|b| **b == b'\n').count();
+                scanned = read.len();
+                !read.starts_with(&[0xff, 0xfe])
+                    && !read.starts_with(&[0xfe, 0xff])
+                    && end.is_some_and(|end| newlines >= end as usize)
+            }
+        }
+    })?;
+    let complete = bytes.len() as u64 == size;
+
+    let (encoding, binary) = detect_encoding(&bytes[..bytes.len().min(DETECTION_BYTES)]);
+
+    // A byte range may cut a character in two, so it is returned as raw bytes
+    let base64 = binary || matches!(range, FileRange::Bytes { .. });
+
+    let mut result = json!({
+        "path": path,
+        "rev": rev,
+        "oid": oid,
+        "size": size,
+        "encoding": encoding,
+        "binary": binary,
+        "content_encoding": if base64 { "base64" } else { "text" }
+    });
+
+    let content = |slice: &[u8]| {
+        if base64 {
+            json!(base64_encode(slice))
+        } else {
+            json!(decode(slice, encoding))
+        }
+    };
+
+    match range {
+        FileRange::Bytes { start, max } => {
+            let start = start.min(size);
+            let end = max.map_or(size, |max| start.saturating_add(max).min(size));
+
+            result["content"] = content(&bytes[start as usize..end as usize]);
+            result["start_byte"] = json!(start);
+            result["end_byte"] = json!(end);
+            result["has_more"] = json!(end < size);
+        }
+        FileRange::Lines { start, end } if !binary => {
+            let text = decode(&bytes, encoding);
+            let mut lines: Vec<&str> = text.split_inclusive('\n').collect();
+
+            // A partial read may end in the middle of a line past the range
+            let total = if complete {
+                Some(lines.len() as u32)
+            } else {
+                lines.retain(|line| line.ends_with('\n'));
+                None
+            };
+            let available = lines.len() as u32;
+
+            let first = start.min(available + 1);
+            let last = end
+                .map_or(available, |end| end.min(available))
+                .max(first - 1);
+
+            result["content"] = json!(lines[(first - 1) as usize..last as usize].concat());
+            result["start_line"] = json!(first);
+            result["end_line"] = json!(last);
+            result["total_lines"] = json!(total);
+            result["has_more"] = json!(!complete || last < available);
+        }
+        FileRange::Lines { .. } => {
+            return Err(ToolError::ExecutionError(format!(
+                "'{}' is binary; use a byte range instead",
+                path
+            )));
+        }
+        FileRange::Whole => {
+            result["content"] = content(&bytes);
+        }
+    }
+
+    Ok(result)
+}
+
+/// How much of a file its encoding is guessed from, like git's binary check
+const DETECTION_BYTES: usize = 8000;
+
+/// Guess the text encoding of the start of a file from a byte order mark or
+/// UTF-8 validity, treating content with NUL bytes as binary like git does
+fn detect_encoding(bytes: &[u8]) -> (&'static str, bool) {
+    if bytes.starts_with(&[0xef, 0xbb, 0xbf]) {
+        return ("utf-8-bom", false);
+    }
+    if bytes.starts_with(&[0xff, 0xfe]) {
+        return ("utf-16le", false);
+    }
+    if bytes.starts_with(&[0xfe, 0xff]) {
+        return ("utf-16be", false);
+    }
+
+    if bytes.contains(&0) {
+        return ("binary", true);
+    }
+
+    match std::str::from_utf8(bytes) {
+        Ok(text) if text.is_ascii() => ("ascii", false),
+        Ok(_) => ("utf-8", false),
+        // The sample may end in the middle of a character
+        Err(e) if e.error_len().is_none() => ("utf-8", false),
+        // Single-byte encodings such as Latin-1 map every byte to a character
+        Err(_) => ("latin-1", false),
+    }
+}
+
+fn decode(bytes: &[u8], encoding: &str) -> String {
+    match encoding {
+        "utf-16le" | "utf-16be" => {
+            let units: Vec<u16> = bytes
+                .chunks_exact(2)
+                .map(|pair| {
+                    if encoding == "utf-16le" {
+                        u16::from_le_bytes([pair[0], pair[1]])
+                    } else {
+                        u16::from_be_bytes([pair[0], pair[1]])
+                    }
+                })
+                .collect();
+            String::from_utf16_lossy(&units)
+                .trim_start_matches('\u{feff}')
+                .to_string()
+        }
+        "latin-1" => bytes.iter().map(|b| char::from(*b)).collect(),
+        _ => String::from_utf8_lossy(bytes)
+            .trim_start_matches('\u{feff}')
+            .to_string(),
+    }
+}
+
+/// Standard base64 with padding, for binary content in JSON
+fn base64_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, b)| n | u32::from(*b) << (16 - 8 * i));
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(char::from(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize]));
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+
+    encoded
+}
@@ -0,0 +1,163 @@
+use crate::tools::commit_record::{COMMIT_RECORD_FORMAT, CommitRecord, split_commit_records};
+use crate::tools::diff::DiffChangeType;
+use crate::tools::{run_git_command, unquote_path};
+use async_trait::async_trait;
+use mcp_core::handler::{ToolError, ToolHandler};
+use schemars::{JsonSchema, schema_for};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+/// Git file history tool implementation
+#[derive(Debug, Default)]
+pub struct GitFileHistoryTool;
+
+#[derive(Deserialize, JsonSchema)]
+struct GitFileHistoryToolParams {
+    #[schemars(description = "The path to the git repository")]
+    repo_path: String,
+    #[schemars(
+        description = "The file whose history to show, as it is named at the starting revision"
+    )]
+    path: String,
+    #[schemars(description = "The revision to start from (defaults to HEAD)")]
+    #[serde(default)]
+    rev: String,
+    #[schemars(description = "The maximum number of commits to return")]
+    #[serde(default)]
+    max_count: u32,
+}
+
+/// One commit that touched the file, with the file's name at that commit
+#[derive(Debug, Serialize)]
+struct FileHistoryEntry {
+    #[serde(flatten)]
+    commit: CommitRecord,
+    path: Option<String>,
+    old_path: Option<String>,
+    change_type: Option<DiffChangeType>,
+    additions: Option<u64>,
+    deletions: Option<u64>,
+}
+
+#[async_trait]
+impl ToolHandler for GitFileHistoryTool {
+    fn name(&self) -> &'static str {
+        "git_file_history"
+    }
+
+    fn description(&self) -> &'static str {
+        "Get the commit history of a single file, following renames"
+    }
+
+    fn schema(&self) -> Value {
+        serde_json::to_value(schema_for!(GitFileHistoryToolParams)).unwrap_or_default()
+    }
+
+    async fn call(&self, params: Value) -> Result<Value, ToolError> {
+        let params: GitFileHistoryToolParams =
+            serde_json::from_value(params).map_err(|e| ToolError::ExecutionError(e.to_string()))?;
+
+        let rev = if params.rev.is_empty() {
+            None
+        } else {
+            Some(params.rev)
+        };
+
+        let max_count = if params.max_count == 0 {
+            None
+        } else {
+            Some(params.max_count)
+        };
+
+        git_file_history(params.repo_path, params.path, rev, max_count).await
+    }
+}
+
+pub async fn git_file_history(
+    repo_path: String,
+    path: String,
+    rev: Option<String>,
+    max_count: Option<u32>,
+) -> Result<Value, ToolError> {
+    let mut args = vec![
+        "log".to_string(),
+        COMMIT_RECORD_FORMAT.to_string(),
+        "--follow".to_string(),
+        "--raw".to_string(),
+        "--numstat".to_string(),
+    ];
+
+    if let Some(count) = max_count {
+        args.push(format!("--max-count={}", count));
+    }
+
+    if let Some(rev) = &rev {
+        args.push(rev.clone());
+    }
+
+    args.push("--".to_string());
+    args.push(path.clone());
+
+    let cmd_args: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+    let log_output = run_git_command(&repo_path, &cmd_args)?;
+
+    let commits: Vec<FileHistoryEntry> = split_commit_records(&log_output)
+        .map(|(commit, changes)| parse_file_change(commit, changes))
+        .collect();
+
+    Ok(json!({
+        "path": path,
+        "commits": commits
+    }))
+}
+
+/// Combine the `--raw` and `--numstat` lines printed after a commit record
+fn parse_file_change(commit: CommitRecord, changes: &str) -> FileHistoryEntry {
+    let mut entry = FileHistoryEntry {
+        commit,
+        path: None,
+        old_path: None,
+        change_type: None,
+        additions: None,
+        deletions: None,
+    };
+
+    for line in changes.lines() {
+        if let Some(raw) = line.strip_prefix(':') {
+            // :<old mode> <new mode> <old oid> <new oid> <status>\t<path>[\t<new path>]
+            if entry.change_type.is_some() {
+                continue;
+            }
+            let Some((meta, paths)) = raw.split_once('\t') else {
+                continue;
+            };
+            let meta: Vec<&str> = meta.split_whitespace().collect();
+            if meta.len() != 5 {
+                continue;
+            }
+
+            let change_type = DiffChangeType::from_status(meta[4], meta[0], meta[1]);
+            let paths: Vec<String> = paths.split('\t').map(unquote_path).collect();
+            match paths.as_slice() {
+                [old, new] => {
+                    entry.old_path = Some(old.clone());
+                    entry.path = Some(new.clone());
+                }
+                [path] => entry.path = Some(path.clone()),
+                _ => {}
+            }
+            entry.change_type = Some(change_type);
+        } else if entry.additions.is_none() && entry.deletions.is_none() {
+            // <added>\t<deleted>\t<path>, with `-` for binary files
+            let mut fields = line.splitn(3, '\t');
+            if let (Some(added), Some(deleted), Some(_)) =
+                (fields.next(), fields.next(), fields.next())
+            {
+                entry.additions = added.parse().ok();
+                entry.deletions = deleted.parse().ok();
+            }
+        }
+    }
+
+    entry
+}
@@ -0,0 +1,85 @@
+use crate::tools::clone::prepare_target;
+use crate::tools::{read_head, run_git_command};
+use async_trait::async_trait;
+use mcp_core::handler::{ToolError, ToolHandler};
+use schemars::{JsonSchema, schema_for};
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+/// Git init tool implementation
+#[derive(Debug, Default)]
+pub struct GitInitTool;
+
+#[derive(Deserialize, JsonSchema)]
+struct GitInitToolParams {
+    #[schemars(description = "The directory to create the repository in")]
+    path: String,
+    #[schemars(description = "Whether to create a bare repository without a working tree")]
+    #[serde(default)]
+    bare: bool,
+    #[schemars(description = "The name of the initial branch (defaults to git's configuration)")]
+    #[serde(default)]
+    initial_branch: String,
+}
+
+#[async_trait]
+impl ToolHandler for GitInitTool {
+    fn name(&self) -> &'static str {
+        "git_init"
+    }
+
+    fn description(&self) -> &'static str {
+        "Create an empty git repository"
+    }
+
+    fn schema(&self) -> Value {
+        serde_json::to_value(schema_for!(GitInitToolParams)).unwrap_or_default()
+    }
+
+    async fn call(&self, params: Value) -> Result<Value, ToolError> {
+        let params: GitInitToolParams =
+            serde_json::from_value(params).map_err(|e| ToolError::ExecutionError(e.to_string()))?;
+
+        let initial_branch = if params.initial_branch.is_empty() {
+            None
+        } else {
+            Some(params.initial_branch)
+        };
+
+        git_init(params.path, params.bare, initial_branch).await
+    }
+}
+
+pub async fn git_init(
+    path: String,
+    bare: bool,
+    initial_branch: Option<String>,
+) -> Result<Value, ToolError> {
+    let (parent, target) = prepare_target(&path)?;
+    let target_str = target.to_string_lossy().to_string();
+    let branch_arg = initial_branch.map(|b| format!("--initial-branch={}", b));
+
+    // An empty template directory keeps system hooks and excludes out of the new repo
+    let mut args = vec!["init", "--template="];
+
+    if bare {
+        args.push("--bare");
+    }
+
+    if let Some(ref b) = branch_arg {
+        args.push(b);
+    }
+
+    args.push("--");
+    args.push(&target_str);
+
+    let init_output = run_git_command(&parent.to_string_lossy(), &args)?;
+
+    Ok(json!({
+        "success": true,
+        "path": target_str,
+        "bare": bare,
+        "head": read_head(&target_str)?,
+        "message": init_output.trim()
+    }))
+}
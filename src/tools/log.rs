@@ -1,4 +1,5 @@
-use crate::tools::run_git_command;
+use crate::tools::commit_record::{COMMIT_RECORD_FORMAT, parse_commit_records};
+use crate::tools::{decode_cursor, encode_cursor, run_git_command};
 use mcp_core::{ToolError, handler::ToolHandler};
 use serde_json::{Value, json};
 
@@ -10,12 +11,24 @@ pub struct GitLogTool;
 struct GitLogToolParams {
     #[schemars(description = "The path to the git repository")]
     repo_path: String,
-    #[schemars(description = "The maximum number of commits to return")]
+    #[schemars(
+        description = "The maximum number of commits to return; cannot be combined with page_size"
+    )]
     #[serde(default)]
     max_count: u32,
     #[schemars(description = "The branch to filter commits by")]
     #[serde(default)]
     branch: String,
+    #[schemars(
+        description = "The number of commits per page; enables pagination and returns next_cursor"
+    )]
+    #[serde(default)]
+    page_size: u32,
+    #[schemars(
+        description = "The next_cursor value returned by a previous call, to fetch the following page"
+    )]
+    #[serde(default)]
+    cursor: String,
 }
 
 #[async_trait::async_trait]
@@ -26,7 +39,7 @@ impl ToolHandler for GitLogTool {
     }
 
     fn description(&self) -> &'static str {
-        "Get the commit history of a git repository"
+        "Get the commit history of a git repository, optionally in pages; each page re-walks the history before it, so deep pages of long histories are slower"
     }
 
     fn schema(&self) -> Value {
@@ -52,81 +65,150 @@ impl ToolHandler for GitLogTool {
             Some(params.max_count)
         };
 
-        git_log(params.repo_path, max_count, branch).await
+        let page_size = if params.page_size == 0 {
+            None
+        } else {
+            Some(params.page_size)
+        };
+
+        let cursor = if params.cursor.is_empty() {
+            None
+        } else {
+            Some(params.cursor)
+        };
+
+        git_log(params.repo_path, max_count, branch, page_size, cursor).await
     }
 }
 
+/// Page size used when a cursor is supplied without an explicit page size
+const DEFAULT_PAGE_SIZE: u32 = 100;
 
 pub async fn git_log(
     repo_path: String,
     max_count: Option<u32>,
     branch: Option<String>,
+    page_size: Option<u32>,
+    cursor: Option<String>,
 ) -> Result<Value, ToolError> {
-    // Create basic command arguments
-    let base_args = vec!["log", "--pretty=format:%H|%an|%ad|%s"];
+    if page_size.is_some() || cursor.is_some() {
+        if max_count.is_some() {
+            return Err(ToolError::ExecutionError(
+                "max_count cannot be combined with page_size or cursor".to_string(),
+            ));
+        }
 
-    // Collect all arguments
-    let mut cmd_args = Vec::new();
-    cmd_args.extend_from_slice(&base_args);
+        return git_log_page(
+            repo_path,
+            branch,
+            page_size.unwrap_or(DEFAULT_PAGE_SIZE),
+            cursor,
+        );
+    }
+
+    // Create basic command arguments
+    let mut cmd_args = vec!["log".to_string(), COMMIT_RECORD_FORMAT.to_string()];
 
     // Handle max_count option
     if let Some(count) = max_count {
-        let count_str = count.to_string();
-        cmd_args.push("-n");
-        cmd_args.push(&count_str[..]);
-
-        // Ensure branch parameter doesn't cause lifetime issues
-        if let Some(ref b) = branch {
-            cmd_args.push(b);
-        }
-
-        let log_output = run_git_command(&repo_path, &cmd_args)?;
-
-        let commits: Vec<Value> = log_output
-            .lines()
-            .filter(|line| !line.is_empty())
-            .map(|line| {
-                let parts: Vec<&str> = line.split('|').collect();
-                if parts.len() >= 4 {
-                    json!({
-                        "hash": parts[0],
-                        "author": parts[1],
-                        "date": parts[2],
-                        "message": parts[3]
-                    })
-                } else {
-                    json!({ "raw": line })
-                }
-            })
-            .collect();
-
-        return Ok(json!({ "commits": commits }));
+        cmd_args.push("-n".to_string());
+        cmd_args.push(count.to_string());
     }
 
-    // If no max_count
-    if let Some(ref b) = branch {
+    // Handle branch option
+    if let Some(b) = branch {
         cmd_args.push(b);
     }
 
+    let cmd_args: Vec<&str> = cmd_args.iter().map(|s| s.as_str()).collect();
     let log_output = run_git_command(&repo_path, &cmd_args)?;
 
-    let commits: Vec<Value> = log_output
-        .lines()
-        .filter(|line| !line.is_empty())
-        .map(|line| {
-            let parts: Vec<&str> = line.split('|').collect();
-            if parts.len() >= 4 {
-                json!({
-                    "hash": parts[0],
-                    "author": parts[1],
-                    "date": parts[2],
-                    "message": parts[3]
-                })
-            } else {
-                json!({ "raw": line })
+    Ok(json!({ "commits": parse_commit_records(&log_output) }))
+}
+
+/// Fetch one page of history.
+///
+/// The cursor records the rev spec, the number of commits already returned and
+/// the oid of the last one. The next page skips to just before that commit and
+/// checks it is still there, so a rewritten history is reported instead of
+/// silently returning a shifted page.
+///
+/// History cannot be resumed from the last commit's parents without changing
+/// the order of a merge-heavy walk, so git skips over the earlier pages again
+/// and a page costs time proportional to its offset.
+fn git_log_page(
+    repo_path: String,
+    branch: Option<String>,
+    page_size: u32,
+    cursor: Option<String>,
+) -> Result<Value, ToolError> {
+    let (rev, offset, last_oid) = match cursor {
+        Some(token) => {
+            let parts = decode_cursor(&token)?;
+            let [rev, offset, oid] = parts.as_slice() else {
+                return Err(ToolError::ExecutionError("Invalid cursor".to_string()));
+            };
+            let offset: u64 = offset
+                .parse()
+                .map_err(|_| ToolError::ExecutionError("Invalid cursor".to_string()))?;
+
+            if let Some(ref b) = branch
+                && b != rev
+            {
+                return Err(ToolError::ExecutionError(format!(
+                    "Cursor was created for '{}', not '{}'",
+                    rev, b
+                )));
             }
-        })
-        .collect();
 
-    Ok(json!({ "commits": commits }))
+            (rev.clone(), offset, Some(oid.clone()))
+        }
+        None => (branch.unwrap_or_else(|| "HEAD".to_string()), 0, None),
+    };
+
+    // Fetch one commit before the page to verify the cursor, and one after it
+    // to learn whether another page exists
+    let skip = offset.saturating_sub(1);
+    let count = u64::from(page_size) + 1 + u64::from(last_oid.is_some());
+
+    let skip_arg = format!("--skip={}", skip);
+    let count_arg = format!("--max-count={}", count);
+    let cmd_args = vec![
+        "log",
+        COMMIT_RECORD_FORMAT,
+        &skip_arg,
+        &count_arg,
+        &rev,
+        "--",
+    ];
+
+    let log_output = run_git_command(&repo_path, &cmd_args)?;
+    let mut commits = parse_commit_records(&log_output);
+
+    if let Some(ref oid) = last_oid {
+        if commits.first().map(|commit| &commit.hash) != Some(oid) {
+            return Err(ToolError::ExecutionError(format!(
+                "Cursor no longer matches the history of '{}'; restart without a cursor",
+                rev
+            )));
+        }
+        commits.remove(0);
+    }
+
+    let has_more = commits.len() > page_size as usize;
+    commits.truncate(page_size as usize);
+
+    let next_cursor = match commits.last() {
+        Some(last) if has_more => Some(encode_cursor(&[
+            &rev,
+            &(offset + commits.len() as u64).to_string(),
+            &last.hash,
+        ])),
+        _ => None,
+    };
+
+    Ok(json!({
+        "commits": commits,
+        "next_cursor": next_cursor
+    }))
 }
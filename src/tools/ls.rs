@@ -0,0 +1,444 @@
+use crate::tools::show::{TreeEntry, parse_ls_tree_entry};
+use crate::tools::{
+    decode_cursor, encode_cursor, run_git_command, run_git_command_records,
+    run_git_command_with_input,
+};
+use async_trait::async_trait;
+use mcp_core::handler::{ToolError, ToolHandler};
+use schemars::{JsonSchema, schema_for};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+/// Git ls tool implementation
+#[derive(Debug, Default)]
+pub struct GitLsTool;
+
+#[derive(Deserialize, JsonSchema)]
+struct GitLsToolParams {
+    #[schemars(description = "The path to the git repository")]
+    repo_path: String,
+    #[schemars(description = "The revision whose tree to list (lists the index if omitted)")]
+    #[serde(default)]
+    rev: String,
+    #[schemars(
+        description = "Whether to list the contents of subdirectories instead of the directories themselves; the index is always listed recursively"
+    )]
+    #[serde(default)]
+    recursive: bool,
+    #[schemars(description = "Only list entries matching these pathspecs")]
+    #[serde(default)]
+    paths: Vec<String>,
+    #[schemars(description = "Whether to also list untracked files in the working tree")]
+    #[serde(default)]
+    untracked: bool,
+    #[schemars(description = "Whether to also list ignored files in the working tree")]
+    #[serde(default)]
+    ignored: bool,
+    #[schemars(
+        description = "The number of entries per page, counting tracked, untracked and ignored files together; enables pagination and returns next_cursor"
+    )]
+    #[serde(default)]
+    page_size: u32,
+    #[schemars(
+        description = "The next_cursor value returned by a previous call, to fetch the following page; the listing options are taken from the cursor"
+    )]
+    #[serde(default)]
+    cursor: String,
+}
+
+/// A tracked entry, with its stage when it is an unmerged index entry
+#[derive(Debug, Serialize)]
+struct LsEntry {
+    #[serde(flatten)]
+    entry: TreeEntry,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stage: Option<u8>,
+}
+
+/// Which untracked files to list besides the tracked entries
+#[derive(Debug, Default, Clone, Copy)]
+pub struct WorktreeOptions {
+    pub untracked: bool,
+    pub ignored: bool,
+}
+
+#[async_trait]
+impl ToolHandler for GitLsTool {
+    fn name(&self) -> &'static str {
+        "git_ls"
+    }
+
+    fn description(&self) -> &'static str {
+        "List the files tracked at a revision or in the index, and optionally untracked and ignored files"
+    }
+
+    fn schema(&self) -> Value {
+        serde_json::to_value(schema_for!(GitLsToolParams)).unwrap_or_default()
+    }
+
+    async fn call(&self, params: Value) -> Result<Value, ToolError> {
+        let params: GitLsToolParams =
+            serde_json::from_value(params).map_err(|e| ToolError::ExecutionError(e.to_string()))?;
+
+        let rev = if params.rev.is_empty() {
+            None
+        } else {
+            Some(params.rev)
+        };
+
+        let page_size = if params.page_size == 0 {
+            None
+        } else {
+            Some(params.page_size)
+        };
+
+        let cursor = if params.cursor.is_empty() {
+            None
+        } else {
+            Some(params.cursor)
+        };
+
+        let worktree = WorktreeOptions {
+            untracked: params.untracked,
+            ignored: params.ignored,
+        };
+
+        git_ls(
+            params.repo_path,
+            rev,
+            params.recursive,
+            params.paths,
+            worktree,
+            page_size,
+            cursor,
+        )
+        .await
+    }
+}
+
+/// Marks a cursor created while listing the index rather than a tree
+const INDEX_SOURCE: &str = "index";
+
+/// The parts of a listing, in the order pages go through them
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    Tracked,
+    Untracked,
+    Ignored,
+}
+
+impl Section {
+    /// The result field the section's entries are returned in
+    fn key(self) -> &'static str {
+        match self {
+            Section::Tracked => "entries",
+            Section::Untracked => "untracked",
+            Section::Ignored => "ignored",
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        [Section::Tracked, Section::Untracked, Section::Ignored]
+            .into_iter()
+            .find(|section| section.key() == key)
+    }
+}
+
+/// What a listing covers. It is carried in the cursor, so every page lists
+/// the same tree, pathspecs and sections as the first one.
+struct Listing {
+    source: String,
+    recursive: bool,
+    paths: Vec<String>,
+    worktree: WorktreeOptions,
+}
+
+impl Listing {
+    fn sections(&self) -> Vec<Section> {
+        let mut sections = vec![Section::Tracked];
+        if self.worktree.untracked {
+            sections.push(Section::Untracked);
+        }
+        if self.worktree.ignored {
+            sections.push(Section::Ignored);
+        }
+        sections
+    }
+
+    fn args(&self, section: Section) -> Vec<&str> {
+        let mut args = match section {
+            // ls-files has no non-recursive mode; the index is always listed in full
+            Section::Tracked if self.source == INDEX_SOURCE => vec!["ls-files", "-z", "--stage"],
+            Section::Tracked => {
+                let mut args = vec!["ls-tree", "-z", "--long"];
+                if self.recursive {
+                    args.push("-r");
+                }
+                args.push(&self.source);
+                args
+            }
+            // Unless recursive, a directory holding only untracked or ignored
+            // files is listed once with a trailing slash
+            Section::Untracked | Section::Ignored => {
+                let mut args = vec!["ls-files", "-z", "--others", "--exclude-standard"];
+                if section == Section::Ignored {
+                    args.push("--ignored");
+                }
+                if !self.recursive {
+                    args.push("--directory");
+                }
+                args
+            }
+        };
+        args.push("--");
+        args.extend(self.paths.iter().map(String::as_str));
+        args
+    }
+
+    /// A cursor resuming `section` after `offset` entries, the last of which was `last_path`
+    fn cursor(&self, section: Section, offset: usize, last_path: &str) -> String {
+        let flag = |set: bool| if set { "1" } else { "0" };
+        let offset = offset.to_string();
+
+        let mut parts = vec![
+            self.source.as_str(),
+            flag(self.recursive),
+            flag(self.worktree.untracked),
+            flag(self.worktree.ignored),
+            section.key(),
+            &offset,
+            last_path,
+        ];
+        parts.extend(self.paths.iter().map(String::as_str));
+
+        encode_cursor(&parts)
+    }
+
+    fn from_cursor(token: &str) -> Result<(Self, Section, usize, Option<String>), ToolError> {
+        let invalid = || ToolError::ExecutionError("Invalid cursor".to_string());
+
+        let parts = decode_cursor(token)?;
+        let [
+            source,
+            recursive,
+            untracked,
+            ignored,
+            section,
+            offset,
+            last_path,
+            paths @ ..,
+        ] = parts.as_slice()
+        else {
+            return Err(invalid());
+        };
+
+        let listing = Listing {
+            source: source.clone(),
+            recursive: recursive == "1",
+            paths: paths.to_vec(),
+            worktree: WorktreeOptions {
+                untracked: untracked == "1",
+                ignored: ignored == "1",
+            },
+        };
+        let section = Section::from_key(section).ok_or_else(invalid)?;
+        let offset: usize = offset.parse().map_err(|_| invalid())?;
+
+        // A page that starts a section has no previous entry to check
+        let last_path = (offset > 0).then(|| last_path.clone());
+
+        Ok((listing, section, offset, last_path))
+    }
+}
+
+/// List one page of tracked entries, followed by untracked and ignored files
+/// when requested.
+///
+/// Output is read a record at a time, so a page of a huge tree never holds
+/// more than the page in memory. As in git_log, the cursor records the number
+/// of entries already returned from the current section and the path of the
+/// last one, which the next page checks before continuing, along with what the
+/// listing covers.
+pub async fn git_ls(
+    repo_path: String,
+    rev: Option<String>,
+    recursive: bool,
+    paths: Vec<String>,
+    worktree: WorktreeOptions,
+    page_size: Option<u32>,
+    cursor: Option<String>,
+) -> Result<Value, ToolError> {
+    let tree_oid = match rev {
+        Some(ref rev) => Some(resolve_tree(&repo_path, rev)?),
+        None => None,
+    };
+
+    let (listing, first_section, mut offset, mut last_path) = match cursor {
+        Some(ref token) => {
+            let (listing, section, offset, last_path) = Listing::from_cursor(token)?;
+
+            if let Some(ref oid) = tree_oid
+                && *oid != listing.source
+            {
+                return Err(ToolError::ExecutionError(format!(
+                    "Cursor was created for another tree than '{}'",
+                    rev.as_deref().unwrap_or_default()
+                )));
+            }
+
+            (listing, section, offset, last_path)
+        }
+        None => {
+            let listing = Listing {
+                source: tree_oid.unwrap_or_else(|| INDEX_SOURCE.to_string()),
+                recursive,
+                paths,
+                worktree,
+            };
+            (listing, Section::Tracked, 0, None)
+        }
+    };
+
+    let mut result = json!({
+        "rev": rev,
+        "tree_oid": if listing.source == INDEX_SOURCE { None } else { Some(&listing.source) },
+        "next_cursor": null
+    });
+    for section in listing.sections() {
+        result[section.key()] = json!([]);
+    }
+
+    let mut remaining = page_size.map(|size| size as usize);
+
+    // Once the page is full, the next section is read with no room left, which
+    // only tells whether it has any entries for a further page
+    for section in listing
+        .sections()
+        .into_iter()
+        .skip_while(|section| *section != first_section)
+    {
+        // Read the entry before the page to verify the cursor
+        let skip = offset.saturating_sub(1);
+        let limit = remaining.map(|n| n + usize::from(last_path.is_some()));
+        let (records, has_more) =
+            run_git_command_records(&repo_path, &listing.args(section), skip, limit)?;
+
+        let mut entries: Vec<(String, Value)> = match section {
+            Section::Tracked if listing.source == INDEX_SOURCE => {
+                parse_ls_files(&repo_path, &records)?
+                    .into_iter()
+                    .map(|entry| (entry.entry.path.clone(), json!(entry)))
+                    .collect()
+            }
+            Section::Tracked => records
+                .iter()
+                .filter_map(|record| parse_ls_tree_entry(record))
+                .map(|entry| (entry.path.clone(), json!(LsEntry { entry, stage: None })))
+                .collect(),
+            Section::Untracked | Section::Ignored => records
+                .into_iter()
+                .map(|path| (path.clone(), json!(path)))
+                .collect(),
+        };
+
+        if let Some(ref path) = last_path {
+            if entries.first().map(|(first, _)| first) != Some(path) {
+                return Err(ToolError::ExecutionError(
+                    "Cursor no longer matches the listing; restart without a cursor".to_string(),
+                ));
+            }
+            entries.remove(0);
+        }
+
+        if let Some(n) = remaining.as_mut() {
+            *n -= entries.len();
+        }
+
+        let returned = entries.len();
+        let last = entries
+            .last()
+            .map(|(path, _)| path.clone())
+            .unwrap_or_default();
+        result[section.key()] = Value::Array(entries.into_iter().map(|(_, entry)| entry).collect());
+
+        if has_more {
+            result["next_cursor"] = json!(listing.cursor(section, offset + returned, &last));
+            break;
+        }
+
+        offset = 0;
+        last_path = None;
+    }
+
+    Ok(result)
+}
+
+fn resolve_tree(repo_path: &str, rev: &str) -> Result<String, ToolError> {
+    let tree = format!("{}^{{tree}}", rev);
+    let oid = run_git_command(
+        repo_path,
+        &["rev-parse", "--verify", "--end-of-options", &tree],
+    )?;
+
+    Ok(oid.trim().to_string())
+}
+
+/// Parse `ls-files --stage` records, `<mode> <oid> <stage>\t<path>`, and look
+/// up the size of each blob since the index does not record it
+fn parse_ls_files(repo_path: &str, records: &[String]) -> Result<Vec<LsEntry>, ToolError> {
+    let mut entries: Vec<LsEntry> = records
+        .iter()
+        .filter_map(|record| {
+            let (info, path) = record.split_once('\t')?;
+            let mut fields = info.split(' ');
+            let mode = fields.next()?;
+            let oid = fields.next()?;
+            let stage: u8 = fields.next()?.parse().ok()?;
+
+            Some(LsEntry {
+                entry: TreeEntry {
+                    mode: mode.to_string(),
+                    // Submodules are recorded as the commit they point at
+                    kind: if mode == "160000" { "commit" } else { "blob" }.to_string(),
+                    oid: oid.to_string(),
+                    size: None,
+                    path: path.to_string(),
+                },
+                stage: (stage > 0).then_some(stage),
+            })
+        })
+        .collect();
+
+    let blobs: Vec<&str> = entries
+        .iter()
+        .filter(|entry| entry.entry.kind == "blob")
+        .map(|entry| entry.entry.oid.as_str())
+        .collect();
+    if blobs.is_empty() {
+        return Ok(entries);
+    }
+
+    let mut input = blobs.join("\n");
+    input.push('\n');
+    let sizes_output = run_git_command_with_input(
+        repo_path,
+        &["cat-file", "--batch-check=%(objectname) %(objectsize)"],
+        input.into_bytes(),
+    )?;
+
+    // Objects missing from the database are reported as "<oid> missing"
+    let sizes: HashMap<&str, u64> = sizes_output
+        .lines()
+        .filter_map(|line| {
+            let (oid, size) = line.split_once(' ')?;
+            Some((oid, size.parse().ok()?))
+        })
+        .collect();
+
+    for entry in entries.iter_mut() {
+        entry.entry.size = sizes.get(entry.entry.oid.as_str()).copied();
+    }
+
+    Ok(entries)
+}
@@ -0,0 +1,235 @@
+use crate::tools::diff::diff_stats;
+use crate::tools::status::{operation_in_progress, read_status};
+use crate::tools::{parse_overwritten_paths, resolve_rev, run_git_command_output};
+use async_trait::async_trait;
+use mcp_core::handler::{ToolError, ToolHandler};
+use schemars::{JsonSchema, schema_for};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+/// Git merge tool implementation
+#[derive(Debug, Default)]
+pub struct GitMergeTool;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeAction {
+    #[default]
+    Merge,
+    Abort,
+    Continue,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct GitMergeToolParams {
+    #[schemars(description = "The path to the git repository")]
+    repo_path: String,
+    #[schemars(
+        description = "Whether to start a merge (default), or abort or continue the one in progress"
+    )]
+    #[serde(default)]
+    action: MergeAction,
+    #[schemars(description = "The commits or branches to merge into the current branch")]
+    #[serde(default)]
+    commits: Vec<String>,
+    #[schemars(description = "Whether to always create a merge commit, even for a fast-forward")]
+    #[serde(default)]
+    no_ff: bool,
+    #[schemars(description = "Whether to refuse the merge unless it is a fast-forward")]
+    #[serde(default)]
+    ff_only: bool,
+    #[schemars(
+        description = "Whether to stage the merged changes without committing or recording the merge"
+    )]
+    #[serde(default)]
+    squash: bool,
+    #[schemars(description = "The message for the merge commit")]
+    #[serde(default)]
+    message: String,
+}
+
+/// Options for starting a merge
+#[derive(Debug, Default)]
+pub struct MergeOptions {
+    pub no_ff: bool,
+    pub ff_only: bool,
+    pub squash: bool,
+    pub message: Option<String>,
+}
+
+#[async_trait]
+impl ToolHandler for GitMergeTool {
+    fn name(&self) -> &'static str {
+        "git_merge"
+    }
+
+    fn description(&self) -> &'static str {
+        "Merge commits or branches into the current branch, or abort or continue a merge"
+    }
+
+    fn schema(&self) -> Value {
+        serde_json::to_value(schema_for!(GitMergeToolParams)).unwrap_or_default()
+    }
+
+    async fn call(&self, params: Value) -> Result<Value, ToolError> {
+        let params: GitMergeToolParams =
+            serde_json::from_value(params).map_err(|e| ToolError::ExecutionError(e.to_string()))?;
+
+        match params.action {
+            MergeAction::Merge => {
+                let options = MergeOptions {
+                    no_ff: params.no_ff,
+                    ff_only: params.ff_only,
+                    squash: params.squash,
+                    message: Some(params.message).filter(|m| !m.is_empty()),
+                };
+
+                git_merge(params.repo_path, params.commits, options).await
+            }
+            action => git_merge_control(params.repo_path, action).await,
+        }
+    }
+}
+
+pub async fn git_merge(
+    repo_path: String,
+    commits: Vec<String>,
+    options: MergeOptions,
+) -> Result<Value, ToolError> {
+    if commits.is_empty() {
+        return Err(ToolError::ExecutionError(
+            "At least one commit or branch to merge is required".to_string(),
+        ));
+    }
+
+    if options.ff_only && (options.no_ff || options.squash) {
+        return Err(ToolError::ExecutionError(
+            "ff_only cannot be combined with no_ff or squash".to_string(),
+        ));
+    }
+
+    let mut args = vec!["merge", "--no-edit"];
+
+    if options.no_ff {
+        args.push("--no-ff");
+    }
+
+    if options.ff_only {
+        args.push("--ff-only");
+    }
+
+    if options.squash {
+        args.push("--squash");
+    }
+
+    if let Some(ref msg) = options.message {
+        args.push("-m");
+        args.push(msg);
+    }
+
+    args.extend(commits.iter().map(String::as_str));
+
+    run_merge(&repo_path, &args, MergeAction::Merge, options.squash)
+}
+
+/// Abort or continue the merge in progress
+pub async fn git_merge_control(repo_path: String, action: MergeAction) -> Result<Value, ToolError> {
+    let args: &[&str] = match action {
+        MergeAction::Abort => &["merge", "--abort"],
+        // `merge --continue` commits through the editor, so accept the prepared message
+        MergeAction::Continue => &["-c", "core.editor=true", "merge", "--continue"],
+        MergeAction::Merge => {
+            return Err(ToolError::ExecutionError(
+                "Expected the abort or continue action".to_string(),
+            ));
+        }
+    };
+
+    run_merge(&repo_path, args, action, false)
+}
+
+fn run_merge(
+    repo_path: &str,
+    args: &[&str],
+    action: MergeAction,
+    squash: bool,
+) -> Result<Value, ToolError> {
+    let old_head = resolve_rev(repo_path, "HEAD")?;
+
+    let output = run_git_command_output(repo_path, args)?;
+    let message = format!("{}{}", output.stdout, output.stderr);
+
+    if !output.success {
+        return merge_failure(repo_path, action, old_head, &output.stderr, &message);
+    }
+
+    let new_head = resolve_rev(repo_path, "HEAD")?;
+
+    let files = match (&old_head, &new_head) {
+        (Some(old), Some(new)) if old != new => diff_stats(repo_path, &[old, new])?,
+        // A squash merge leaves its result staged rather than committed
+        (Some(old), _) if squash => diff_stats(repo_path, &["--cached", old])?,
+        _ => Vec::new(),
+    };
+
+    Ok(json!({
+        "success": true,
+        "action": action,
+        "old_head": old_head,
+        "new_head": new_head,
+        "fast_forward": output.stdout.contains("Fast-forward"),
+        "up_to_date": output.stdout.contains("Already up to date"),
+        "squashed": squash,
+        "files_changed": files,
+        "output": message.trim()
+    }))
+}
+
+/// Turn an expected merge failure into a typed result, or an error otherwise
+fn merge_failure(
+    repo_path: &str,
+    action: MergeAction,
+    old_head: Option<String>,
+    stderr: &str,
+    message: &str,
+) -> Result<Value, ToolError> {
+    let overwritten = parse_overwritten_paths(stderr);
+    if !overwritten.is_empty() {
+        return Ok(json!({
+            "success": false,
+            "error": "local_changes_would_be_overwritten",
+            "action": action,
+            "old_head": old_head,
+            "conflicting_paths": overwritten,
+            "message": message.trim()
+        }));
+    }
+
+    let conflicts = read_status(repo_path, false)?.conflicts();
+    if !conflicts.is_empty() {
+        return Ok(json!({
+            "success": false,
+            "error": "conflict",
+            "action": action,
+            "old_head": old_head,
+            "in_progress": operation_in_progress(repo_path)?,
+            "conflicts": conflicts,
+            "message": message.trim()
+        }));
+    }
+
+    if stderr.contains("Not possible to fast-forward") {
+        return Ok(json!({
+            "success": false,
+            "error": "not_fast_forward",
+            "action": action,
+            "old_head": old_head,
+            "message": message.trim()
+        }));
+    }
+
+    Err(ToolError::ExecutionError(format!(
+        "Git command failed: {}",
+        stderr
+    )))
+}
@@ -0,0 +1,181 @@
+use crate::tools::status::IndexStage;
+use crate::tools::{resolve_rev, run_git_command_output};
+use async_trait::async_trait;
+use mcp_core::handler::{ToolError, ToolHandler};
+use schemars::{JsonSchema, schema_for};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+/// Git merge preview tool implementation
+#[derive(Debug, Default)]
+pub struct GitMergePreviewTool;
+
+#[derive(Deserialize, JsonSchema)]
+struct GitMergePreviewToolParams {
+    #[schemars(description = "The path to the git repository")]
+    repo_path: String,
+    #[schemars(description = "The branch or commit to merge into (defaults to HEAD)")]
+    #[serde(default)]
+    ours: String,
+    #[schemars(description = "The branch or commit to merge")]
+    theirs: String,
+}
+
+/// One informational or conflict message from `merge-tree`
+#[derive(Debug, Serialize)]
+struct MergeMessage {
+    paths: Vec<String>,
+    #[serde(rename = "type")]
+    kind: String,
+    message: String,
+}
+
+/// A file the merge could not resolve on its own
+#[derive(Debug, Serialize)]
+struct PreviewConflict {
+    path: String,
+    stages: Vec<IndexStage>,
+    messages: Vec<String>,
+}
+
+#[async_trait]
+impl ToolHandler for GitMergePreviewTool {
+    fn name(&self) -> &'static str {
+        "git_merge_preview"
+    }
+
+    fn description(&self) -> &'static str {
+        "Compute a merge in memory and report whether it would conflict, without touching the index, working tree or refs"
+    }
+
+    fn schema(&self) -> Value {
+        serde_json::to_value(schema_for!(GitMergePreviewToolParams)).unwrap_or_default()
+    }
+
+    async fn call(&self, params: Value) -> Result<Value, ToolError> {
+        let params: GitMergePreviewToolParams =
+            serde_json::from_value(params).map_err(|e| ToolError::ExecutionError(e.to_string()))?;
+
+        let ours = if params.ours.is_empty() {
+            None
+        } else {
+            Some(params.ours)
+        };
+
+        git_merge_preview(params.repo_path, ours, params.theirs).await
+    }
+}
+
+pub async fn git_merge_preview(
+    repo_path: String,
+    ours: Option<String>,
+    theirs: String,
+) -> Result<Value, ToolError> {
+    let ours = ours.unwrap_or_else(|| "HEAD".to_string());
+
+    let mut oids = Vec::new();
+    for rev in [&ours, &theirs] {
+        let commit = format!("{}^{{commit}}", rev);
+        match resolve_rev(&repo_path, &commit)? {
+            Some(oid) => oids.push(oid),
+            None => {
+                return Err(ToolError::ExecutionError(format!(
+                    "'{}' is not a commit",
+                    rev
+                )));
+            }
+        }
+    }
+
+    // Needs git 2.38+; the resulting tree is written to the object database
+    // but nothing refers to it. The resolved oids are passed so that no
+    // revision can be taken for an option.
+    let output = run_git_command_output(
+        &repo_path,
+        &["merge-tree", "--write-tree", "-z", &oids[0], &oids[1]],
+    )?;
+
+    // Exit status 0 means a clean merge and 1 a merge with conflicts; anything
+    // else is an error. Stderr may hold warnings in either case.
+    let clean = match output.code {
+        Some(0) => true,
+        Some(1) => false,
+        _ => {
+            return Err(ToolError::ExecutionError(format!(
+                "Git command failed: {}",
+                output.stderr
+            )));
+        }
+    };
+
+    let (tree_oid, conflicts, messages) = parse_merge_tree(&output.stdout);
+
+    Ok(json!({
+        "clean": clean,
+        "ours": ours,
+        "ours_oid": oids[0],
+        "theirs": theirs,
+        "theirs_oid": oids[1],
+        "tree_oid": tree_oid,
+        "conflicts": conflicts,
+        "messages": messages
+    }))
+}
+
+/// Parse `merge-tree --write-tree -z` output: the tree oid, the stages of
+/// conflicted files, an empty record, then the informational messages
+fn parse_merge_tree(output: &str) -> (String, Vec<PreviewConflict>, Vec<MergeMessage>) {
+    let mut records = output.split('\0');
+    let tree_oid = records.next().unwrap_or_default().to_string();
+
+    let mut conflicts: Vec<PreviewConflict> = Vec::new();
+    for record in records.by_ref() {
+        if record.is_empty() {
+            break;
+        }
+
+        // <mode> <oid> <stage>\t<path>
+        let Some((info, path)) = record.split_once('\t') else {
+            continue;
+        };
+        let fields: Vec<&str> = info.split(' ').collect();
+        let [mode, oid, stage] = fields.as_slice() else {
+            continue;
+        };
+        let stage = IndexStage::new(stage.parse().unwrap_or_default(), mode, oid);
+
+        match conflicts.last_mut() {
+            Some(conflict) if conflict.path == path => conflict.stages.push(stage),
+            _ => conflicts.push(PreviewConflict {
+                path: path.to_string(),
+                stages: vec![stage],
+                messages: Vec::new(),
+            }),
+        }
+    }
+
+    // <path count>, that many paths, <type>, <message>
+    let mut messages = Vec::new();
+    while let Some(count) = records.next() {
+        let Ok(count) = count.parse::<usize>() else {
+            break;
+        };
+        let paths: Vec<String> = records.by_ref().take(count).map(str::to_string).collect();
+        let kind = records.next().unwrap_or_default().to_string();
+        let message = records.next().unwrap_or_default().trim_end().to_string();
+
+        for conflict in conflicts.iter_mut() {
+            if kind.starts_with("CONFLICT") && paths.contains(&conflict.path) {
+                conflict.messages.push(message.clone());
+            }
+        }
+
+        messages.push(MergeMessage {
+            paths,
+            kind,
+            message,
+        });
+    }
+
+    (tree_oid, conflicts, messages)
+}
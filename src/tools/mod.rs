@@ -1,40 +1,250 @@
 //! Git tools implementations
 
 pub mod add;
+pub mod blame;
 pub mod branch;
+pub mod branch_manage;
+pub mod cherry_pick;
+pub mod clone;
 pub mod commit;
+pub(crate) mod commit_record;
+pub mod conflict_resolve;
+pub mod conflict_show;
+pub mod conflicts_list;
 pub mod diff;
+pub mod fetch;
+pub mod file_at_rev;
+pub mod file_history;
+pub mod init;
 pub mod log;
+pub mod ls;
+pub mod merge;
+pub mod merge_preview;
 pub mod pull;
 pub mod push;
+pub mod rebase;
+pub mod remote;
 pub mod reset;
+pub mod revert;
+pub mod sequencer;
+pub mod show;
+pub mod stash;
 pub mod status;
+pub mod switch;
+pub mod tag;
 pub mod time_filtered_log;
 
 // Re-export all tools to make them publicly accessible
 pub use add::GitAddTool;
+pub use blame::GitBlameTool;
 pub use branch::GitBranchesTool;
+pub use branch_manage::GitBranchTool;
+pub use cherry_pick::GitCherryPickTool;
+pub use clone::GitCloneTool;
 pub use commit::GitCommitTool;
+pub use conflict_resolve::GitConflictResolveTool;
+pub use conflict_show::GitConflictShowTool;
+pub use conflicts_list::GitConflictsListTool;
 pub use diff::GitDiffTool;
+pub use fetch::GitFetchTool;
+pub use file_at_rev::GitFileAtRevTool;
+pub use file_history::GitFileHistoryTool;
+pub use init::GitInitTool;
 pub use log::GitLogTool;
+pub use ls::GitLsTool;
+pub use merge::GitMergeTool;
+pub use merge_preview::GitMergePreviewTool;
 pub use pull::GitPullTool;
 pub use push::GitPushTool;
+pub use rebase::GitRebaseTool;
+pub use remote::GitRemoteTool;
 pub use reset::GitResetTool;
+pub use revert::GitRevertTool;
+pub use show::GitShowTool;
+pub use stash::GitStashTool;
 pub use status::GitStatusTool;
+pub use switch::GitSwitchTool;
+pub use tag::GitTagTool;
 pub use time_filtered_log::GitTimeFilteredLogTool;
 
 use mcp_core::handler::ToolError;
-use std::process::Command;
+use serde_json::{Value, json};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::process::{Child, ChildStdout, Command, Stdio};
+use std::thread::JoinHandle;
 
-/// Helper function to run Git commands and handle errors
-pub(crate) fn run_git_command(repo_path: &str, args: &[&str]) -> Result<String, ToolError> {
-    let output = Command::new("git")
+/// Captured result of a git invocation that is allowed to fail
+pub(crate) struct GitOutput {
+    pub success: bool,
+    /// The exit code, or None when git was killed by a signal
+    pub code: Option<i32>,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+/// Start building a Git command for the repository. Messages are forced to
+/// English, since expected failures are recognised from git's own wording.
+fn git_command(repo_path: &str, args: &[&str]) -> Command {
+    let mut command = Command::new("git");
+    command
         .arg("-C")
         .arg(repo_path)
         .args(args)
+        .env("LC_ALL", "C");
+    command
+}
+
+/// Helper function to run Git commands and handle errors
+pub(crate) fn run_git_command(repo_path: &str, args: &[&str]) -> Result<String, ToolError> {
+    let output = run_git_command_output(repo_path, args)?;
+
+    if !output.success {
+        return Err(ToolError::ExecutionError(format!(
+            "Git command failed: {}",
+            output.stderr
+        )));
+    }
+
+    Ok(output.stdout)
+}
+
+/// Run a Git command and return its output even when it exits unsuccessfully,
+/// for callers that turn expected failures (conflicts, refusals) into results
+pub(crate) fn run_git_command_output(
+    repo_path: &str,
+    args: &[&str],
+) -> Result<GitOutput, ToolError> {
+    run_git_command_with_env(repo_path, args, &[])
+}
+
+/// Like [`run_git_command_output`], with extra environment variables such as
+/// `GIT_EDITOR` for commands that would otherwise open an editor
+pub(crate) fn run_git_command_with_env(
+    repo_path: &str,
+    args: &[&str],
+    envs: &[(&str, &str)],
+) -> Result<GitOutput, ToolError> {
+    let output = git_command(repo_path, args)
+        .envs(envs.iter().copied())
         .output()
         .map_err(|e| ToolError::ExecutionError(format!("Failed to execute git: {}", e)))?;
 
+    Ok(GitOutput {
+        success: output.status.success(),
+        code: output.status.code(),
+        stdout: String::from_utf8_lossy(&output.stdout).to_string(),
+        stderr: String::from_utf8_lossy(&output.stderr).to_string(),
+    })
+}
+
+/// Run a Git command and read its raw stdout until `done` is satisfied with
+/// what has been read so far, then stop git, so a small part of a large blob
+/// never requires reading all of it. Returns everything read.
+pub(crate) fn run_git_command_prefix(
+    repo_path: &str,
+    args: &[&str],
+    mut done: impl FnMut(&[u8]) -> bool,
+) -> Result<Vec<u8>, ToolError> {
+    let (mut child, mut stdout, stderr) = spawn_git_streaming(repo_path, args)?;
+
+    let mut bytes = Vec::new();
+    let mut chunk = [0u8; 64 * 1024];
+    let mut stopped_early = false;
+
+    loop {
+        if done(&bytes) {
+            stopped_early = true;
+            break;
+        }
+
+        let read = stdout
+            .read(&mut chunk)
+            .map_err(|e| ToolError::ExecutionError(format!("Failed to read git output: {}", e)))?;
+        if read == 0 {
+            break;
+        }
+        bytes.extend_from_slice(&chunk[..read]);
+    }
+
+    finish_git_streaming(&mut child, stderr, stopped_early)?;
+
+    Ok(bytes)
+}
+
+/// Start a Git command whose stdout is read as a stream. Stderr is collected
+/// on another thread, so a command that writes a lot of it cannot block on a
+/// full pipe while stdout is being read.
+fn spawn_git_streaming(
+    repo_path: &str,
+    args: &[&str],
+) -> Result<(Child, ChildStdout, JoinHandle<Vec<u8>>), ToolError> {
+    let mut child = git_command(repo_path, args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| ToolError::ExecutionError(format!("Failed to execute git: {}", e)))?;
+
+    let stdout = child.stdout.take().expect("stdout is piped");
+    let mut stderr = child.stderr.take().expect("stderr is piped");
+    let stderr = std::thread::spawn(move || {
+        let mut buffer = Vec::new();
+        let _ = stderr.read_to_end(&mut buffer);
+        buffer
+    });
+
+    Ok((child, stdout, stderr))
+}
+
+/// Wait for a command started by [`spawn_git_streaming`]. A command whose
+/// remaining output is not needed is killed instead; otherwise its exit status
+/// is checked.
+fn finish_git_streaming(
+    child: &mut Child,
+    stderr: JoinHandle<Vec<u8>>,
+    stopped_early: bool,
+) -> Result<(), ToolError> {
+    if stopped_early {
+        let _ = child.kill();
+    }
+
+    let status = child
+        .wait()
+        .map_err(|e| ToolError::ExecutionError(format!("Failed to execute git: {}", e)))?;
+    let stderr = stderr.join().unwrap_or_default();
+
+    if !stopped_early && !status.success() {
+        return Err(ToolError::ExecutionError(format!(
+            "Git command failed: {}",
+            String::from_utf8_lossy(&stderr)
+        )));
+    }
+
+    Ok(())
+}
+
+/// Run a Git command with data written to its stdin, e.g. object names for
+/// `cat-file --batch-check`
+pub(crate) fn run_git_command_with_input(
+    repo_path: &str,
+    args: &[&str],
+    input: Vec<u8>,
+) -> Result<String, ToolError> {
+    let mut child = git_command(repo_path, args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| ToolError::ExecutionError(format!("Failed to execute git: {}", e)))?;
+
+    // Write from another thread so a full stdout pipe cannot block the input
+    let mut stdin = child.stdin.take().expect("stdin is piped");
+    let writer = std::thread::spawn(move || stdin.write_all(&input));
+
+    let output = child
+        .wait_with_output()
+        .map_err(|e| ToolError::ExecutionError(format!("Failed to execute git: {}", e)))?;
+    let _ = writer.join();
+
     if !output.status.success() {
         return Err(ToolError::ExecutionError(format!(
             "Git command failed: {}",
@@ -44,3 +254,248 @@ pub(crate) fn run_git_command(repo_path: &str, args: &[&str]) -> Result<String,
 
     Ok(String::from_utf8_lossy(&output.stdout).to_string())
 }
+
+/// Run a Git command with NUL-delimited output and read it one record at a
+/// time, skipping `skip` records and keeping at most `limit`, so that huge
+/// listings are never buffered whole. Also returns whether more records followed.
+pub(crate) fn run_git_command_records(
+    repo_path: &str,
+    args: &[&str],
+    skip: usize,
+    limit: Option<usize>,
+) -> Result<(Vec<String>, bool), ToolError> {
+    let (mut child, stdout, stderr) = spawn_git_streaming(repo_path, args)?;
+
+    let mut records = Vec::new();
+    let mut has_more = false;
+
+    for record in BufReader::new(stdout).split(b'\0').skip(skip) {
+        let record = record
+            .map_err(|e| ToolError::ExecutionError(format!("Failed to read git output: {}", e)))?;
+
+        if limit.is_some_and(|limit| records.len() >= limit) {
+            has_more = true;
+            break;
+        }
+        records.push(String::from_utf8_lossy(&record).to_string());
+    }
+
+    // The rest of the output is not needed once another record is known to follow
+    finish_git_streaming(&mut child, stderr, has_more)?;
+
+    Ok((records, has_more))
+}
+
+/// Paths git refused to overwrite when updating the working tree
+#[derive(Debug, Default, serde::Serialize)]
+pub(crate) struct OverwrittenPaths {
+    pub local_changes: Vec<String>,
+    pub untracked: Vec<String>,
+}
+
+impl OverwrittenPaths {
+    pub fn is_empty(&self) -> bool {
+        self.local_changes.is_empty() && self.untracked.is_empty()
+    }
+}
+
+/// Collect the tab-indented paths git lists after "... would be overwritten by ..."
+pub(crate) fn parse_overwritten_paths(stderr: &str) -> OverwrittenPaths {
+    let mut paths = OverwrittenPaths::default();
+    let mut target: Option<&mut Vec<String>> = None;
+
+    for line in stderr.lines() {
+        if line.contains("would be overwritten by") || line.contains("would be removed by") {
+            target = Some(if line.contains("untracked") {
+                &mut paths.untracked
+            } else {
+                &mut paths.local_changes
+            });
+        } else if let Some(path) = line.strip_prefix('\t') {
+            if let Some(list) = target.as_mut() {
+                list.push(unquote_path(path.trim_end()));
+            }
+        } else {
+            target = None;
+        }
+    }
+
+    paths
+}
+
+/// Undo git's C-style quoting of paths that contain special characters
+pub(crate) fn unquote_path(path: &str) -> String {
+    let Some(inner) = path
+        .strip_prefix('"')
+        .and_then(|rest| rest.strip_suffix('"'))
+    else {
+        return path.to_string();
+    };
+
+    let mut bytes = Vec::with_capacity(inner.len());
+    let mut chars = inner.bytes().peekable();
+    while let Some(b) = chars.next() {
+        if b != b'\\' {
+            bytes.push(b);
+            continue;
+        }
+        match chars.next() {
+            Some(b'n') => bytes.push(b'\n'),
+            Some(b't') => bytes.push(b'\t'),
+            Some(b'r') => bytes.push(b'\r'),
+            Some(b'a') => bytes.push(0x07),
+            Some(b'b') => bytes.push(0x08),
+            Some(b'f') => bytes.push(0x0c),
+            Some(b'v') => bytes.push(0x0b),
+            Some(d @ b'0'..=b'7') => {
+                // Octal escapes encode the raw bytes of non-ASCII characters
+                let mut value = u32::from(d - b'0');
+                for _ in 0..2 {
+                    match chars.peek() {
+                        Some(&o @ b'0'..=b'7') => {
+                            value = value * 8 + u32::from(o - b'0');
+                            chars.next();
+                        }
+                        _ => break,
+                    }
+                }
+                bytes.push(value as u8);
+            }
+            Some(other) => bytes.push(other),
+            None => bytes.push(b'\\'),
+        }
+    }
+
+    String::from_utf8_lossy(&bytes).to_string()
+}
+
+/// Encode pagination state into an opaque cursor token
+pub(crate) fn encode_cursor(parts: &[&str]) -> String {
+    parts
+        .join("\0")
+        .bytes()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Decode a cursor token produced by [`encode_cursor`]
+pub(crate) fn decode_cursor(cursor: &str) -> Result<Vec<String>, ToolError> {
+    let invalid = || ToolError::ExecutionError("Invalid cursor".to_string());
+
+    if !cursor.len().is_multiple_of(2) {
+        return Err(invalid());
+    }
+
+    let bytes = (0..cursor.len())
+        .step_by(2)
+        .map(|i| {
+            cursor
+                .get(i..i + 2)
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+        })
+        .collect::<Option<Vec<u8>>>()
+        .ok_or_else(invalid)?;
+
+    let decoded = String::from_utf8(bytes).map_err(|_| invalid())?;
+
+    Ok(decoded.split('\0').map(str::to_string).collect())
+}
+
+/// The top-level directory of the working tree, which status paths are
+/// relative to even when `repo_path` is a subdirectory
+pub(crate) fn read_toplevel(repo_path: &str) -> Result<String, ToolError> {
+    let toplevel = run_git_command(repo_path, &["rev-parse", "--show-toplevel"])?;
+
+    Ok(toplevel.trim_end_matches('\n').to_string())
+}
+
+/// Resolve a revision, returning None when it does not exist (e.g. an unborn HEAD)
+pub(crate) fn resolve_rev(repo_path: &str, rev: &str) -> Result<Option<String>, ToolError> {
+    let output = run_git_command_output(repo_path, &["rev-parse", "--verify", "--quiet", rev])?;
+
+    Ok(Some(output.stdout.trim().to_string()).filter(|oid| output.success && !oid.is_empty()))
+}
+
+/// Describe HEAD as its branch (if any) and commit oid (if any commit exists)
+pub(crate) fn read_head(repo_path: &str) -> Result<Value, ToolError> {
+    let branch = run_git_command_output(repo_path, &["symbolic-ref", "--short", "-q", "HEAD"])?;
+    let branch = Some(branch.stdout.trim().to_string()).filter(|b| branch.success && !b.is_empty());
+
+    Ok(json!({
+        "detached": branch.is_none(),
+        "branch": branch,
+        "oid": resolve_rev(repo_path, "HEAD")?
+    }))
+}
+
+/// Hide credentials embedded in a URL. For http(s) the whole user part is
+/// hidden, since tokens are often passed as the user name; for other schemes
+/// only a password is hidden.
+pub(crate) fn redact_url(url: &str) -> String {
+    let Some((scheme, rest)) = url.split_once("://") else {
+        return url.to_string();
+    };
+
+    let authority_end = rest.find('/').unwrap_or(rest.len());
+    let Some(at) = rest[..authority_end].rfind('@') else {
+        return url.to_string();
+    };
+    let (userinfo, host) = (&rest[..at], &rest[at + 1..]);
+
+    let redacted = redact_userinfo(scheme, userinfo);
+
+    format!("{}://{}@{}", scheme, redacted, host)
+}
+
+/// The user info of a URL with the part [`redact_url`] hides replaced
+fn redact_userinfo(scheme: &str, userinfo: &str) -> String {
+    match userinfo.split_once(':') {
+        _ if scheme.eq_ignore_ascii_case("http") || scheme.eq_ignore_ascii_case("https") => {
+            "***".to_string()
+        }
+        Some((user, _)) => format!("{}:***", user),
+        None => userinfo.to_string(),
+    }
+}
+
+/// Hide credentials in every URL mentioned in free text such as git's stderr,
+/// which often echoes the URL it failed to reach
+pub(crate) fn redact_urls(text: &str) -> String {
+    let is_delimiter = |c: char| c.is_ascii_whitespace() || c == '\'' || c == '"';
+
+    let mut redacted = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(scheme_end) = rest.find("://") {
+        let start = rest[..scheme_end].rfind(is_delimiter).map_or(0, |i| i + 1);
+        let end = rest[scheme_end..]
+            .find(is_delimiter)
+            .map_or(rest.len(), |i| scheme_end + i);
+
+        redacted.push_str(&rest[..start]);
+        redacted.push_str(&redact_url(&rest[start..end]));
+        rest = &rest[end..];
+    }
+    redacted.push_str(rest);
+
+    redacted
+}
+
+/// Hide credentials in git's output about `url`: in every URL it mentions,
+/// and in the URL's own `user:password@` wherever it appears, since some
+/// messages echo the host without a scheme (e.g. "unable to look up ...")
+pub(crate) fn redact_output(text: &str, url: &str) -> String {
+    let redacted = redact_urls(text);
+
+    let Some((scheme, rest)) = url.split_once("://") else {
+        return redacted;
+    };
+    let authority = &rest[..rest.find('/').unwrap_or(rest.len())];
+
+    match authority.rsplit_once('@') {
+        Some((userinfo, _)) => redacted.replace(
+            &format!("{}@", userinfo),
+            &format!("{}@", redact_userinfo(scheme, userinfo)),
+        ),
+        None => redacted,
+    }
+}
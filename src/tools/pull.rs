@@ -1,14 +1,26 @@
-use crate::tools::run_git_command;
+use crate::tools::commit_record::{COMMIT_RECORD_FORMAT, parse_commit_records};
+use crate::tools::diff::diff_stats;
+use crate::tools::status::{operation_in_progress, read_status};
+use crate::tools::{parse_overwritten_paths, resolve_rev, run_git_command, run_git_command_output};
 use async_trait::async_trait;
 use mcp_core::handler::{ToolError, ToolHandler};
 use schemars::{JsonSchema, schema_for};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::{Value, json};
 
 /// Git pull tool implementation
 #[derive(Debug, Default)]
 pub struct GitPullTool;
 
+/// How fetched changes are integrated into the current branch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PullStrategy {
+    Merge,
+    Rebase,
+    FfOnly,
+}
+
 #[derive(Deserialize, JsonSchema)]
 struct GitPullToolParams {
     #[schemars(description = "The path to the git repository")]
@@ -19,6 +31,16 @@ struct GitPullToolParams {
     #[schemars(description = "The branch to pull")]
     #[serde(default)]
     branch: String,
+    #[schemars(
+        description = "How to integrate the fetched changes: merge, rebase or ff_only (defaults to the repository's pull configuration)"
+    )]
+    #[serde(default)]
+    strategy: Option<PullStrategy>,
+    #[schemars(
+        description = "Whether to stash local changes before pulling and re-apply them afterwards"
+    )]
+    #[serde(default)]
+    autostash: bool,
 }
 
 #[async_trait]
@@ -51,7 +73,14 @@ impl ToolHandler for GitPullTool {
             Some(params.branch)
         };
 
-        git_pull(params.repo_path, remote, branch).await
+        git_pull(
+            params.repo_path,
+            remote,
+            branch,
+            params.strategy,
+            params.autostash,
+        )
+        .await
     }
 }
 
@@ -59,22 +88,124 @@ pub async fn git_pull(
     repo_path: String,
     remote: Option<String>,
     branch: Option<String>,
+    strategy: Option<PullStrategy>,
+    autostash: bool,
 ) -> Result<Value, ToolError> {
     let remote_name = remote.unwrap_or_else(|| "origin".to_string());
 
+    let old_head = resolve_rev(&repo_path, "HEAD")?;
+
     let mut args = Vec::new();
     args.push("pull");
+
+    match strategy {
+        Some(PullStrategy::Merge) => args.extend(["--no-rebase", "--no-edit"]),
+        Some(PullStrategy::Rebase) => args.push("--rebase"),
+        Some(PullStrategy::FfOnly) => args.push("--ff-only"),
+        None => args.push("--no-edit"),
+    }
+
+    if autostash {
+        args.push("--autostash");
+    }
+
     args.push(&remote_name);
 
     if let Some(ref branch_name) = branch {
         args.push(branch_name);
     }
 
-    let pull_output = run_git_command(&repo_path, &args)?;
+    let output = run_git_command_output(&repo_path, &args)?;
+    let message = format!("{}{}", output.stdout, output.stderr);
+
+    if !output.success {
+        return pull_failure(
+            &repo_path,
+            &remote_name,
+            strategy,
+            old_head,
+            &output.stderr,
+            &message,
+        );
+    }
+
+    let new_head = resolve_rev(&repo_path, "HEAD")?;
+
+    // FETCH_HEAD is what was integrated, so old..FETCH_HEAD is what came in
+    // regardless of whether it was merged, rebased onto or fast-forwarded
+    let (commits, files) = match (&old_head, &new_head) {
+        (Some(old), Some(new)) if old != new => {
+            let range = format!("{}..FETCH_HEAD", old);
+            let log_output = run_git_command(&repo_path, &["log", COMMIT_RECORD_FORMAT, &range])?;
+            (
+                parse_commit_records(&log_output),
+                diff_stats(&repo_path, &[old, new])?,
+            )
+        }
+        _ => (Vec::new(), Vec::new()),
+    };
 
     Ok(json!({
         "success": true,
         "remote": remote_name,
-        "output": pull_output.trim()
+        "strategy": strategy,
+        "old_head": old_head,
+        "new_head": new_head,
+        "commits": commits,
+        "files_changed": files,
+        "output": message.trim()
     }))
 }
+
+/// Turn an expected pull failure into a typed result, or an error otherwise
+fn pull_failure(
+    repo_path: &str,
+    remote_name: &str,
+    strategy: Option<PullStrategy>,
+    old_head: Option<String>,
+    stderr: &str,
+    message: &str,
+) -> Result<Value, ToolError> {
+    let overwritten = parse_overwritten_paths(stderr);
+    if !overwritten.is_empty() {
+        return Ok(json!({
+            "success": false,
+            "error": "local_changes_would_be_overwritten",
+            "remote": remote_name,
+            "strategy": strategy,
+            "old_head": old_head,
+            "conflicting_paths": overwritten,
+            "message": message.trim()
+        }));
+    }
+
+    let conflicts = read_status(repo_path, false)?.conflicts();
+    if !conflicts.is_empty() {
+        return Ok(json!({
+            "success": false,
+            "error": "conflict",
+            "remote": remote_name,
+            "strategy": strategy,
+            "old_head": old_head,
+            "in_progress": operation_in_progress(repo_path)?,
+            "conflicts": conflicts,
+            "message": message.trim()
+        }));
+    }
+
+    if strategy == Some(PullStrategy::FfOnly) && stderr.contains("fast-forward") {
+        return Ok(json!({
+            "success": false,
+            "error": "not_fast_forward",
+            "remote": remote_name,
+            "strategy": strategy,
+            "old_head": old_head,
+            "message": message.trim()
+        }));
+    }
+
+    Err(ToolError::ExecutionError(format!(
+        "Git command failed: {}",
+        stderr
+    )))
+}
@@ -1,8 +1,8 @@
-use crate::tools::run_git_command;
+use crate::tools::run_git_command_output;
 use async_trait::async_trait;
 use mcp_core::handler::{ToolError, ToolHandler};
 use schemars::JsonSchema;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::{Value, json};
 
 /// Git push tool implementation
@@ -19,9 +19,77 @@ struct GitPushToolParams {
     #[schemars(description = "The branch to push")]
     #[serde(default)]
     branch: String,
-    #[schemars(description = "Whether to force push")]
+    #[schemars(description = "Whether to force push (prefer force_with_lease)")]
     #[serde(default)]
     force: bool,
+    #[schemars(
+        description = "Whether to force push only if the remote branch is still where we last saw it"
+    )]
+    #[serde(default)]
+    force_with_lease: bool,
+    #[schemars(
+        description = "The oid the remote branch must currently point at for force_with_lease (requires branch)"
+    )]
+    #[serde(default)]
+    expected_oid: String,
+    #[schemars(description = "Whether to set the pushed branch's upstream to the remote branch")]
+    #[serde(default)]
+    set_upstream: bool,
+    #[schemars(description = "Whether to only report what would be pushed")]
+    #[serde(default)]
+    dry_run: bool,
+    #[schemars(description = "Whether to delete the branch on the remote instead of pushing it")]
+    #[serde(default)]
+    delete: bool,
+    #[schemars(description = "Whether to push all tags")]
+    #[serde(default)]
+    tags: bool,
+    #[schemars(description = "A single tag to push")]
+    #[serde(default)]
+    tag: String,
+}
+
+/// Options that change how refs are pushed
+#[derive(Debug, Default)]
+pub struct PushOptions {
+    pub force: bool,
+    pub force_with_lease: bool,
+    pub expected_oid: Option<String>,
+    pub set_upstream: bool,
+    pub dry_run: bool,
+    pub delete: bool,
+    pub tags: bool,
+    pub tag: Option<String>,
+}
+
+/// Result of pushing a single ref
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum PushRefStatus {
+    Ok,
+    Rejected,
+    RemoteRejected,
+    UpToDate,
+}
+
+/// How a successfully pushed ref moved on the remote
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum PushRefUpdate {
+    New,
+    FastForward,
+    Forced,
+    Deleted,
+}
+
+#[derive(Debug, Serialize)]
+struct PushRefResult {
+    local_ref: Option<String>,
+    remote_ref: String,
+    status: PushRefStatus,
+    update: Option<PushRefUpdate>,
+    summary: String,
+    reason: Option<String>,
 }
 
 #[async_trait]
@@ -53,14 +121,31 @@ impl ToolHandler for GitPushTool {
         } else {
             Some(params.branch)
         };
-        
-        let force = if params.force {
-            Some(true)
+
+        let expected_oid = if params.expected_oid.is_empty() {
+            None
         } else {
+            Some(params.expected_oid)
+        };
+
+        let tag = if params.tag.is_empty() {
             None
+        } else {
+            Some(params.tag)
+        };
+
+        let options = PushOptions {
+            force: params.force,
+            force_with_lease: params.force_with_lease || expected_oid.is_some(),
+            expected_oid,
+            set_upstream: params.set_upstream,
+            dry_run: params.dry_run,
+            delete: params.delete,
+            tags: params.tags,
+            tag,
         };
 
-        git_push(params.repo_path, remote, branch, force).await
+        git_push(params.repo_path, remote, branch, options).await
     }
 }
 
@@ -68,27 +153,118 @@ pub async fn git_push(
     repo_path: String,
     remote: Option<String>,
     branch: Option<String>,
-    force: Option<bool>,
+    options: PushOptions,
 ) -> Result<Value, ToolError> {
     let remote_name = remote.unwrap_or_else(|| "origin".to_string());
 
+    if options.delete && branch.is_none() {
+        return Err(ToolError::ExecutionError(
+            "delete requires a branch".to_string(),
+        ));
+    }
+
+    // An explicit lease names the ref it protects
+    let lease = match (&options.expected_oid, &branch) {
+        (Some(oid), Some(branch_name)) => {
+            Some(format!("--force-with-lease={}:{}", branch_name, oid))
+        }
+        (Some(_), None) => {
+            return Err(ToolError::ExecutionError(
+                "expected_oid requires a branch".to_string(),
+            ));
+        }
+        (None, _) if options.force_with_lease => Some("--force-with-lease".to_string()),
+        (None, _) => None,
+    };
+
     let mut args = Vec::new();
     args.push("push");
+    args.push("--porcelain");
+
+    if let Some(ref lease) = lease {
+        args.push(lease);
+    } else if options.force {
+        args.push("--force");
+    }
+
+    if options.set_upstream {
+        args.push("--set-upstream");
+    }
+
+    if options.dry_run {
+        args.push("--dry-run");
+    }
+
+    if options.delete {
+        args.push("--delete");
+    }
+
+    if options.tags {
+        args.push("--tags");
+    }
+
     args.push(&remote_name);
 
     if let Some(ref branch_name) = branch {
         args.push(branch_name);
     }
 
-    if force.unwrap_or(false) {
-        args.push("--force");
+    // Push a single tag by its full ref so it cannot be mistaken for a branch
+    let tag_ref = options.tag.map(|t| format!("refs/tags/{}", t));
+    if let Some(ref tag_ref) = tag_ref {
+        args.push(tag_ref);
     }
 
-    let push_output = run_git_command(&repo_path, &args)?;
+    let output = run_git_command_output(&repo_path, &args)?;
+    let refs: Vec<PushRefResult> = output.stdout.lines().filter_map(parse_push_line).collect();
+
+    // Rejections make git exit non-zero but are reported per ref
+    if !output.success && refs.is_empty() {
+        return Err(ToolError::ExecutionError(format!(
+            "Git command failed: {}",
+            output.stderr
+        )));
+    }
 
     Ok(json!({
-        "success": true,
+        "success": output.success,
         "remote": remote_name,
-        "output": push_output.trim()
+        "dry_run": options.dry_run,
+        "refs": refs,
+        "output": output.stderr.trim()
     }))
 }
+
+/// Parse `<flag>\t<from>:<to>\t<summary> [(<reason>)]` from `push --porcelain`
+fn parse_push_line(line: &str) -> Option<PushRefResult> {
+    let fields: Vec<&str> = line.splitn(3, '\t').collect();
+    let [flag, refs, summary] = fields.as_slice() else {
+        return None;
+    };
+
+    let (status, update) = match *flag {
+        " " => (PushRefStatus::Ok, Some(PushRefUpdate::FastForward)),
+        "+" => (PushRefStatus::Ok, Some(PushRefUpdate::Forced)),
+        "-" => (PushRefStatus::Ok, Some(PushRefUpdate::Deleted)),
+        "*" => (PushRefStatus::Ok, Some(PushRefUpdate::New)),
+        "=" => (PushRefStatus::UpToDate, None),
+        "!" if summary.starts_with("[remote rejected]") => (PushRefStatus::RemoteRejected, None),
+        "!" => (PushRefStatus::Rejected, None),
+        _ => return None,
+    };
+
+    let (from, to) = refs.split_once(':')?;
+    let (summary, reason) = match summary.split_once(" (") {
+        Some((summary, reason)) => (summary, Some(reason.trim_end_matches(')').to_string())),
+        None => (*summary, None),
+    };
+
+    Some(PushRefResult {
+        local_ref: Some(from.to_string()).filter(|f| !f.is_empty()),
+        remote_ref: to.to_string(),
+        status,
+        update,
+        summary: summary.to_string(),
+        reason,
+    })
+}
@@ -0,0 +1,341 @@
+use crate::tools::status::{operation_in_progress, read_status};
+use crate::tools::{
+    parse_overwritten_paths, read_head, resolve_rev, run_git_command, run_git_command_with_env,
+};
+use async_trait::async_trait;
+use mcp_core::handler::{ToolError, ToolHandler};
+use schemars::{JsonSchema, schema_for};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::path::{Path, PathBuf};
+
+/// Git rebase tool implementation
+#[derive(Debug, Default)]
+pub struct GitRebaseTool;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RebaseAction {
+    #[default]
+    Rebase,
+    Continue,
+    Skip,
+    Abort,
+}
+
+/// A command in an interactive rebase todo list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TodoCommand {
+    Pick,
+    Reword,
+    Squash,
+    Fixup,
+    Drop,
+    Edit,
+}
+
+/// One line of an interactive rebase todo list
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct TodoStep {
+    #[schemars(
+        description = "What to do with the commit: pick, reword, squash, fixup, drop or edit"
+    )]
+    pub action: TodoCommand,
+    #[schemars(description = "The commit oid")]
+    pub commit: String,
+    #[schemars(
+        description = "The new commit message for reword or squash (keeps the existing message if omitted)"
+    )]
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct GitRebaseToolParams {
+    #[schemars(description = "The path to the git repository")]
+    repo_path: String,
+    #[schemars(
+        description = "Whether to start a rebase (default), or continue, skip or abort the one in progress"
+    )]
+    #[serde(default)]
+    action: RebaseAction,
+    #[schemars(description = "The upstream to rebase the current branch onto")]
+    #[serde(default)]
+    upstream: String,
+    #[schemars(
+        description = "Replay the commits onto this commit instead of the upstream (--onto)"
+    )]
+    #[serde(default)]
+    onto: String,
+    #[schemars(
+        description = "The todo list for an interactive rebase, in order; commits left out are dropped"
+    )]
+    #[serde(default)]
+    todo: Option<Vec<TodoStep>>,
+    #[schemars(
+        description = "Whether to stash local changes before rebasing and re-apply them afterwards"
+    )]
+    #[serde(default)]
+    autostash: bool,
+}
+
+#[async_trait]
+impl ToolHandler for GitRebaseTool {
+    fn name(&self) -> &'static str {
+        "git_rebase"
+    }
+
+    fn description(&self) -> &'static str {
+        "Rebase the current branch onto an upstream, optionally following an interactive todo list, or continue, skip or abort a rebase"
+    }
+
+    fn schema(&self) -> Value {
+        serde_json::to_value(schema_for!(GitRebaseToolParams)).unwrap_or_default()
+    }
+
+    async fn call(&self, params: Value) -> Result<Value, ToolError> {
+        let params: GitRebaseToolParams =
+            serde_json::from_value(params).map_err(|e| ToolError::ExecutionError(e.to_string()))?;
+
+        let onto = if params.onto.is_empty() {
+            None
+        } else {
+            Some(params.onto)
+        };
+
+        match params.action {
+            RebaseAction::Rebase => {
+                git_rebase(
+                    params.repo_path,
+                    params.upstream,
+                    onto,
+                    params.todo,
+                    params.autostash,
+                )
+                .await
+            }
+            action => git_rebase_control(params.repo_path, action).await,
+        }
+    }
+}
+
+/// Directory inside `.git` holding the todo list and messages of a rebase
+/// started by this tool
+const REBASE_STATE_DIR: &str = "mcp-rebase";
+
+pub async fn git_rebase(
+    repo_path: String,
+    upstream: String,
+    onto: Option<String>,
+    todo: Option<Vec<TodoStep>>,
+    autostash: bool,
+) -> Result<Value, ToolError> {
+    if upstream.is_empty() {
+        return Err(ToolError::ExecutionError(
+            "An upstream to rebase onto is required".to_string(),
+        ));
+    }
+
+    let mut args = vec!["rebase"];
+
+    if autostash {
+        args.push("--autostash");
+    }
+
+    if let Some(ref target) = onto {
+        args.push("--onto");
+        args.push(target);
+    }
+
+    // The todo list replaces the one git generates: GIT_SEQUENCE_EDITOR copies
+    // it over git's file instead of opening an editor
+    let sequence_editor = match todo {
+        Some(steps) => {
+            let todo_path = write_todo(&repo_path, &steps)?;
+            args.push("--interactive");
+            Some(format!("cp {}", shell_quote(&todo_path.to_string_lossy())))
+        }
+        None => None,
+    };
+
+    args.push(&upstream);
+
+    let mut envs = vec![("GIT_EDITOR", "true")];
+    if let Some(ref editor) = sequence_editor {
+        envs.push(("GIT_SEQUENCE_EDITOR", editor));
+    }
+
+    run_rebase(&repo_path, &args, &envs, RebaseAction::Rebase)
+}
+
+/// Continue, skip or abort the rebase in progress
+pub async fn git_rebase_control(
+    repo_path: String,
+    action: RebaseAction,
+) -> Result<Value, ToolError> {
+    let flag = match action {
+        RebaseAction::Continue => "--continue",
+        RebaseAction::Skip => "--skip",
+        RebaseAction::Abort => "--abort",
+        RebaseAction::Rebase => {
+            return Err(ToolError::ExecutionError(
+                "Expected the continue, skip or abort action".to_string(),
+            ));
+        }
+    };
+
+    // Keep the message of a conflicted pick instead of opening an editor
+    run_rebase(
+        &repo_path,
+        &["rebase", flag],
+        &[("GIT_EDITOR", "true")],
+        action,
+    )
+}
+
+fn run_rebase(
+    repo_path: &str,
+    args: &[&str],
+    envs: &[(&str, &str)],
+    action: RebaseAction,
+) -> Result<Value, ToolError> {
+    let old_head = resolve_rev(repo_path, "HEAD")?;
+
+    let output = run_git_command_with_env(repo_path, args, envs)?;
+    let message = strip_progress(&format!("{}{}", output.stdout, output.stderr));
+
+    let in_progress = operation_in_progress(repo_path)?;
+    if in_progress != Some("rebase") {
+        // The todo list and messages are only needed until the rebase ends
+        let _ = std::fs::remove_dir_all(rebase_state_dir(repo_path)?);
+    }
+
+    if !output.success {
+        return rebase_failure(repo_path, action, old_head, &output.stderr, &message);
+    }
+
+    // A rebase that succeeded but is still in progress stopped at an `edit` step
+    let stopped = in_progress == Some("rebase");
+
+    Ok(json!({
+        "success": true,
+        "action": action,
+        "stopped": stopped,
+        "stopped_at": if stopped { resolve_rev(repo_path, "REBASE_HEAD")? } else { None },
+        "old_head": old_head,
+        "head": read_head(repo_path)?,
+        "output": message.trim()
+    }))
+}
+
+/// Turn an expected rebase failure into a typed result, or an error otherwise
+fn rebase_failure(
+    repo_path: &str,
+    action: RebaseAction,
+    old_head: Option<String>,
+    stderr: &str,
+    message: &str,
+) -> Result<Value, ToolError> {
+    let overwritten = parse_overwritten_paths(stderr);
+    if !overwritten.is_empty() {
+        return Ok(json!({
+            "success": false,
+            "error": "local_changes_would_be_overwritten",
+            "action": action,
+            "old_head": old_head,
+            "conflicting_paths": overwritten,
+            "message": message.trim()
+        }));
+    }
+
+    let conflicts = read_status(repo_path, false)?.conflicts();
+    if !conflicts.is_empty() {
+        return Ok(json!({
+            "success": false,
+            "error": "conflict",
+            "action": action,
+            "old_head": old_head,
+            "in_progress": operation_in_progress(repo_path)?,
+            "stopped_at": resolve_rev(repo_path, "REBASE_HEAD")?,
+            "conflicts": conflicts,
+            "message": message.trim()
+        }));
+    }
+
+    Err(ToolError::ExecutionError(format!(
+        "Git command failed: {}",
+        stderr
+    )))
+}
+
+/// Write the todo list, turning new messages into `exec git commit --amend`
+/// lines so they survive stops for conflicts or edits
+fn write_todo(repo_path: &str, steps: &[TodoStep]) -> Result<PathBuf, ToolError> {
+    let dir = rebase_state_dir(repo_path)?;
+    let write_error = |e: std::io::Error| {
+        ToolError::ExecutionError(format!("Failed to write rebase todo list: {}", e))
+    };
+
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).map_err(write_error)?;
+
+    let mut todo = String::new();
+    for (i, step) in steps.iter().enumerate() {
+        let commit = step.commit.trim();
+        if commit.is_empty() || commit.contains(char::is_whitespace) {
+            return Err(ToolError::ExecutionError(format!(
+                "Invalid commit '{}' in rebase todo list",
+                step.commit
+            )));
+        }
+
+        let (command, message) = match (step.action, &step.message) {
+            // Amending right after the pick replaces the message without an editor
+            (TodoCommand::Reword, msg) => ("pick", msg.as_ref()),
+            (TodoCommand::Squash, msg) => ("squash", msg.as_ref()),
+            (TodoCommand::Pick, _) => ("pick", None),
+            (TodoCommand::Fixup, _) => ("fixup", None),
+            (TodoCommand::Drop, _) => ("drop", None),
+            (TodoCommand::Edit, _) => ("edit", None),
+        };
+
+        todo.push_str(&format!("{} {}\n", command, commit));
+
+        if let Some(msg) = message {
+            let message_path = dir.join(format!("message-{}", i));
+            std::fs::write(&message_path, msg).map_err(write_error)?;
+            todo.push_str(&format!(
+                "exec git commit --amend --allow-empty --no-verify --quiet --file={}\n",
+                shell_quote(&message_path.to_string_lossy())
+            ));
+        }
+    }
+
+    let todo_path = dir.join("git-rebase-todo");
+    std::fs::write(&todo_path, todo).map_err(write_error)?;
+
+    Ok(todo_path)
+}
+
+fn rebase_state_dir(repo_path: &str) -> Result<PathBuf, ToolError> {
+    let git_dir = run_git_command(repo_path, &["rev-parse", "--absolute-git-dir"])?;
+
+    Ok(Path::new(git_dir.trim()).join(REBASE_STATE_DIR))
+}
+
+/// Drop the `Rebasing (n/m)` progress lines git redraws with carriage returns
+fn strip_progress(output: &str) -> String {
+    output
+        .lines()
+        .filter_map(|line| line.rsplit('\r').next())
+        .map(|line| line.replace("\u{1b}[K", ""))
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Quote a value for the shell git uses to run editors and exec lines
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
@@ -0,0 +1,262 @@
+use crate::tools::{redact_url, run_git_command, run_git_command_output};
+use async_trait::async_trait;
+use mcp_core::handler::{ToolError, ToolHandler};
+use schemars::{JsonSchema, schema_for};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+/// Git remote tool implementation
+#[derive(Debug, Default)]
+pub struct GitRemoteTool;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RemoteAction {
+    #[default]
+    List,
+    Add,
+    Remove,
+    Rename,
+    SetUrl,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct GitRemoteToolParams {
+    #[schemars(description = "The path to the git repository")]
+    repo_path: String,
+    #[schemars(description = "The operation to perform (defaults to list)")]
+    #[serde(default)]
+    action: RemoteAction,
+    #[schemars(description = "The remote to operate on")]
+    #[serde(default)]
+    name: String,
+    #[schemars(description = "The URL when adding a remote or setting its URL")]
+    #[serde(default)]
+    url: String,
+    #[schemars(description = "The new name when renaming")]
+    #[serde(default)]
+    new_name: String,
+    #[schemars(description = "Whether set_url changes the push URL instead of the fetch URL")]
+    #[serde(default)]
+    push: bool,
+    #[schemars(
+        description = "Whether to ask remotes for their HEAD branch when it is not recorded locally (needs network access)"
+    )]
+    #[serde(default)]
+    query_remote: bool,
+}
+
+/// One configured remote
+#[derive(Debug, Default, Serialize)]
+struct RemoteInfo {
+    name: String,
+    urls: Vec<String>,
+    push_urls: Vec<String>,
+    fetch_refspecs: Vec<String>,
+    head_branch: Option<String>,
+}
+
+#[async_trait]
+impl ToolHandler for GitRemoteTool {
+    fn name(&self) -> &'static str {
+        "git_remote"
+    }
+
+    fn description(&self) -> &'static str {
+        "List remotes with their URLs and HEAD branch, or add, remove, rename a remote or set its URL"
+    }
+
+    fn schema(&self) -> Value {
+        serde_json::to_value(schema_for!(GitRemoteToolParams)).unwrap_or_default()
+    }
+
+    async fn call(&self, params: Value) -> Result<Value, ToolError> {
+        let params: GitRemoteToolParams =
+            serde_json::from_value(params).map_err(|e| ToolError::ExecutionError(e.to_string()))?;
+
+        let url = if params.url.is_empty() {
+            None
+        } else {
+            Some(params.url)
+        };
+
+        let new_name = if params.new_name.is_empty() {
+            None
+        } else {
+            Some(params.new_name)
+        };
+
+        match params.action {
+            RemoteAction::List => git_remote_list(params.repo_path, params.query_remote).await,
+            action => {
+                git_remote(
+                    params.repo_path,
+                    action,
+                    params.name,
+                    url,
+                    new_name,
+                    params.push,
+                )
+                .await
+            }
+        }
+    }
+}
+
+pub async fn git_remote_list(repo_path: String, query_remote: bool) -> Result<Value, ToolError> {
+    Ok(json!({ "remotes": read_remotes(&repo_path, query_remote)? }))
+}
+
+pub async fn git_remote(
+    repo_path: String,
+    action: RemoteAction,
+    name: String,
+    url: Option<String>,
+    new_name: Option<String>,
+    push: bool,
+) -> Result<Value, ToolError> {
+    if name.is_empty() {
+        return Err(ToolError::ExecutionError(
+            "A remote name is required".to_string(),
+        ));
+    }
+
+    let require = |value: &Option<String>, what: &str| {
+        value.clone().ok_or_else(|| {
+            ToolError::ExecutionError(format!("{} is required to {} a remote", what, verb(action)))
+        })
+    };
+
+    let mut args = vec!["remote".to_string()];
+    match action {
+        RemoteAction::Add => {
+            args.extend(["add".to_string(), "--".to_string(), name.clone()]);
+            args.push(require(&url, "url")?);
+        }
+        RemoteAction::Remove => {
+            args.extend(["remove".to_string(), "--".to_string(), name.clone()]);
+        }
+        RemoteAction::Rename => {
+            args.extend(["rename".to_string(), "--".to_string(), name.clone()]);
+            args.push(require(&new_name, "new_name")?);
+        }
+        RemoteAction::SetUrl => {
+            args.push("set-url".to_string());
+            if push {
+                args.push("--push".to_string());
+            }
+            args.extend(["--".to_string(), name.clone()]);
+            args.push(require(&url, "url")?);
+        }
+        RemoteAction::List => return git_remote_list(repo_path, false).await,
+    }
+
+    let cmd_args: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+    run_git_command(&repo_path, &cmd_args)?;
+
+    let remote = match action {
+        RemoteAction::Rename => new_name.unwrap_or(name),
+        _ => name,
+    };
+
+    Ok(json!({
+        "success": true,
+        "action": action,
+        "remote": remote,
+        "remotes": read_remotes(&repo_path, false)?
+    }))
+}
+
+fn verb(action: RemoteAction) -> &'static str {
+    match action {
+        RemoteAction::List => "list",
+        RemoteAction::Add => "add",
+        RemoteAction::Remove => "remove",
+        RemoteAction::Rename => "rename",
+        RemoteAction::SetUrl => "set the URL of",
+    }
+}
+
+/// Read every remote from the configuration in one pass
+fn read_remotes(repo_path: &str, query_remote: bool) -> Result<Vec<RemoteInfo>, ToolError> {
+    let names = run_git_command(repo_path, &["remote"])?;
+    let mut remotes: Vec<RemoteInfo> = names
+        .lines()
+        .map(|name| RemoteInfo {
+            name: name.to_string(),
+            ..RemoteInfo::default()
+        })
+        .collect();
+
+    // Exits with 1 when nothing matches, e.g. in a repository without remotes
+    let config =
+        run_git_command_output(repo_path, &["config", "-z", "--get-regexp", r"^remote\."])?;
+
+    // Each record is "<key>\n<value>"; remote names may themselves contain dots
+    for record in config.stdout.split('\0') {
+        let Some((key, value)) = record.split_once('\n') else {
+            continue;
+        };
+        let Some(rest) = key.strip_prefix("remote.") else {
+            continue;
+        };
+
+        for remote in remotes.iter_mut() {
+            let Some(field) = rest
+                .strip_prefix(remote.name.as_str())
+                .and_then(|field| field.strip_prefix('.'))
+            else {
+                continue;
+            };
+
+            match field {
+                "url" => remote.urls.push(redact_url(value)),
+                "pushurl" => remote.push_urls.push(redact_url(value)),
+                "fetch" => remote.fetch_refspecs.push(value.to_string()),
+                _ => {}
+            }
+        }
+    }
+
+    for remote in remotes.iter_mut() {
+        // Pushes go to the fetch URLs unless push URLs are configured
+        if remote.push_urls.is_empty() {
+            remote.push_urls = remote.urls.clone();
+        }
+
+        remote.head_branch = read_head_branch(repo_path, &remote.name, query_remote)?;
+    }
+
+    Ok(remotes)
+}
+
+/// The branch a remote's HEAD points at, from `refs/remotes/<name>/HEAD` or,
+/// if allowed, by asking the remote
+fn read_head_branch(
+    repo_path: &str,
+    name: &str,
+    query_remote: bool,
+) -> Result<Option<String>, ToolError> {
+    let head_ref = format!("refs/remotes/{}/HEAD", name);
+    let local = run_git_command_output(repo_path, &["symbolic-ref", "--quiet", &head_ref])?;
+    let prefix = format!("refs/remotes/{}/", name);
+    if let Some(branch) = local.stdout.trim().strip_prefix(&prefix)
+        && local.success
+    {
+        return Ok(Some(branch.to_string()));
+    }
+
+    if !query_remote {
+        return Ok(None);
+    }
+
+    // ref: refs/heads/main<TAB>HEAD; an unreachable remote just has no known HEAD
+    let remote = run_git_command_output(repo_path, &["ls-remote", "--symref", "--", name, "HEAD"])?;
+
+    Ok(remote
+        .stdout
+        .lines()
+        .filter_map(|line| line.strip_prefix("ref: refs/heads/"))
+        .find_map(|line| line.strip_suffix("\tHEAD"))
+        .map(str::to_string))
+}
@@ -1,21 +1,56 @@
-use crate::tools::run_git_command;
+use crate::tools::status::read_status;
+use crate::tools::{resolve_rev, run_git_command};
 use async_trait::async_trait;
 use mcp_core::handler::{ToolError, ToolHandler};
 use schemars::{JsonSchema, schema_for};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::{Value, json};
+use std::time::{SystemTime, UNIX_EPOCH};
 
 /// Git reset tool implementation
 #[derive(Debug, Default)]
 pub struct GitResetTool;
 
+/// What a reset updates besides HEAD
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ResetMode {
+    Soft,
+    Mixed,
+    Hard,
+    Keep,
+    Merge,
+}
+
+impl ResetMode {
+    fn flag(self) -> &'static str {
+        match self {
+            ResetMode::Soft => "--soft",
+            ResetMode::Mixed => "--mixed",
+            ResetMode::Hard => "--hard",
+            ResetMode::Keep => "--keep",
+            ResetMode::Merge => "--merge",
+        }
+    }
+}
+
+/// Namespace for refs that record the state before a reset
+const BACKUP_REF_PREFIX: &str = "refs/mcp-backup/";
+
 #[derive(Deserialize, JsonSchema)]
 struct GitResetToolParams {
     #[schemars(description = "The path to the git repository")]
     repo_path: String,
     #[schemars(description = "The path(s) to reset, or patterns to match. Use '.' for all files.")]
     path: String,
-    #[schemars(description = "Whether to perform a hard reset (WARNING: discards all local changes)")]
+    #[schemars(
+        description = "The reset mode: soft, mixed (default), hard (WARNING: discards local changes), keep or merge"
+    )]
+    #[serde(default)]
+    mode: Option<ResetMode>,
+    #[schemars(
+        description = "Whether to perform a hard reset (WARNING: discards all local changes); same as mode 'hard'"
+    )]
     #[serde(default)]
     hard: bool,
     #[schemars(description = "The commit or branch to reset to (defaults to HEAD)")]
@@ -41,20 +76,40 @@ impl ToolHandler for GitResetTool {
         let params: GitResetToolParams =
             serde_json::from_value(params).map_err(|e| ToolError::ExecutionError(e.to_string()))?;
 
-        git_reset(params.repo_path, params.path, params.hard, params.target).await
+        let mode = match params.mode {
+            Some(mode) if params.hard && mode != ResetMode::Hard => {
+                return Err(ToolError::ExecutionError(
+                    "'hard' conflicts with the requested mode; use only 'mode'".to_string(),
+                ));
+            }
+            Some(mode) => Some(mode),
+            None if params.hard => Some(ResetMode::Hard),
+            None => None,
+        };
+
+        git_reset(params.repo_path, params.path, mode, params.target).await
     }
 }
 
 pub async fn git_reset(
     repo_path: String,
     path: String,
-    hard: bool,
+    mode: Option<ResetMode>,
     target: Option<String>,
 ) -> Result<Value, ToolError> {
+    let whole_tree = path.is_empty() || path == ".";
+
+    // Only mixed resets can be limited to paths
+    if !whole_tree && mode.is_some_and(|m| m != ResetMode::Mixed) {
+        return Err(ToolError::ExecutionError(
+            "Only mixed resets can be limited to paths".to_string(),
+        ));
+    }
+
     let mut args = vec!["reset"];
 
-    if hard {
-        args.push("--hard");
+    if let Some(mode) = mode {
+        args.push(mode.flag());
     }
 
     // If target is provided, add it
@@ -63,28 +118,147 @@ pub async fn git_reset(
     }
 
     // Add the file path
-    if !path.is_empty() && path != "." {
+    if !whole_tree {
         args.push("--");
         args.push(&path);
     }
-    
-    let reset_output = run_git_command(&repo_path, &args)?;
-    
-    // Get the status after resetting
-    let status_output = run_git_command(&repo_path, &["status", "--porcelain"])?;
-    let status_lines: Vec<&str> = status_output.lines().collect();
+
+    let old_head = resolve_rev(&repo_path, "HEAD")?;
+
+    // The commit a whole-tree reset moves HEAD to, so that no backup is made
+    // for a target that does not resolve or a reset that leaves HEAD in place
+    let new_target = if whole_tree && old_head.is_some() {
+        let target_ref = target.as_deref().unwrap_or("HEAD");
+        match resolve_rev(&repo_path, &format!("{}^{{commit}}", target_ref))? {
+            Some(oid) => Some(oid),
+            None => {
+                return Err(ToolError::ExecutionError(format!(
+                    "'{}' is not a commit",
+                    target_ref
+                )));
+            }
+        }
+    } else {
+        None
+    };
+
+    // Uncommitted changes a hard reset discards are captured up front; `stash
+    // create` only writes an unreferenced commit, so a failed reset leaves nothing
+    let worktree_oid = if new_target.is_some() && mode == Some(ResetMode::Hard) {
+        let stash_oid = run_git_command(&repo_path, &["stash", "create", "git_reset backup"])?;
+        Some(stash_oid.trim().to_string()).filter(|oid| !oid.is_empty())
+    } else {
+        None
+    };
+
+    // Record where HEAD (and, for hard resets, the working tree) was before
+    // resetting, so the previous state can be restored with another reset
+    let old_commit = match (&old_head, &new_target) {
+        (Some(old), Some(new)) if old != new => Some(old.clone()),
+        _ => None,
+    };
+
+    let backup_name = if old_commit.is_some() || worktree_oid.is_some() {
+        unused_backup_name(&repo_path)?
+    } else {
+        String::new()
+    };
+
+    let mut backups = Vec::new();
+    let backup_ref = match old_commit {
+        Some(oid) => Some(create_backup_ref(
+            &repo_path,
+            backup_name.clone(),
+            &oid,
+            &mut backups,
+        )?),
+        None => None,
+    };
+    let worktree_backup_ref = match worktree_oid {
+        Some(oid) => Some(create_backup_ref(
+            &repo_path,
+            format!("{}-worktree", backup_name),
+            &oid,
+            &mut backups,
+        )?),
+        None => None,
+    };
+
+    let reset_output = match run_git_command(&repo_path, &args) {
+        Ok(output) => output,
+        Err(e) => {
+            delete_refs(&repo_path, &backups);
+            return Err(e);
+        }
+    };
+
+    let status = read_status(&repo_path, false)?;
 
     Ok(json!({
         "success": true,
-        "message": if reset_output.is_empty() { 
-            if hard {
-                "Hard reset performed successfully"
-            } else {
-                "Files unstaged successfully"
+        "mode": mode.unwrap_or(ResetMode::Mixed),
+        "old_head": old_head,
+        "new_head": resolve_rev(&repo_path, "HEAD")?,
+        "backup_ref": backup_ref,
+        "worktree_backup_ref": worktree_backup_ref,
+        "message": if reset_output.is_empty() {
+            match mode {
+                Some(ResetMode::Hard) => "Hard reset performed successfully",
+                Some(ResetMode::Soft) => "Soft reset performed successfully",
+                _ if whole_tree => "Reset performed successfully",
+                _ => "Files unstaged successfully",
             }
-        } else { 
-            &reset_output 
+        } else {
+            &reset_output
         },
-        "status": status_lines
+        "status": {
+            "branch": status.branch,
+            "entries": status.entries,
+            "is_clean": status.is_clean()
+        }
     }))
-} 
\ No newline at end of file
+}
+
+/// A backup ref name under [`BACKUP_REF_PREFIX`] that is not taken yet, for
+/// itself or its `-worktree` companion, even for resets in the same millisecond
+fn unused_backup_name(repo_path: &str) -> Result<String, ToolError> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or_default();
+
+    let mut name = format!("{}{}", BACKUP_REF_PREFIX, timestamp);
+    let mut counter = 1;
+    while resolve_rev(repo_path, &name)?.is_some()
+        || resolve_rev(repo_path, &format!("{}-worktree", name))?.is_some()
+    {
+        name = format!("{}{}-{}", BACKUP_REF_PREFIX, timestamp, counter);
+        counter += 1;
+    }
+
+    Ok(name)
+}
+
+/// Create a backup ref, refusing to overwrite an existing one; on failure the
+/// backups created so far are removed again
+fn create_backup_ref(
+    repo_path: &str,
+    name: String,
+    oid: &str,
+    created: &mut Vec<String>,
+) -> Result<String, ToolError> {
+    if let Err(e) = run_git_command(repo_path, &["update-ref", &name, oid, ""]) {
+        delete_refs(repo_path, created);
+        return Err(e);
+    }
+    created.push(name.clone());
+
+    Ok(name)
+}
+
+/// Remove backup refs again after the reset they were made for failed
+fn delete_refs(repo_path: &str, refs: &[String]) {
+    for name in refs {
+        let _ = run_git_command(repo_path, &["update-ref", "-d", name]);
+    }
+}
@@ -0,0 +1,76 @@
+use crate::tools::sequencer::{SequencerAction, SequencerOptions, run_sequencer};
+use async_trait::async_trait;
+use mcp_core::handler::{ToolError, ToolHandler};
+use schemars::{JsonSchema, schema_for};
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Git revert tool implementation
+#[derive(Debug, Default)]
+pub struct GitRevertTool;
+
+#[derive(Deserialize, JsonSchema)]
+struct GitRevertToolParams {
+    #[schemars(description = "The path to the git repository")]
+    repo_path: String,
+    #[schemars(
+        description = "Whether to start reverting (default), or continue, skip or abort the sequence in progress"
+    )]
+    #[serde(default)]
+    action: SequencerAction,
+    #[schemars(description = "The commits or ranges (e.g. 'main~3..main') to revert")]
+    #[serde(default)]
+    commits: Vec<String>,
+    #[schemars(
+        description = "The parent number (starting from 1) to keep when reverting a merge commit"
+    )]
+    #[serde(default)]
+    mainline: u32,
+    #[schemars(
+        description = "Whether to apply the reverse changes to the index and working tree without committing"
+    )]
+    #[serde(default)]
+    no_commit: bool,
+}
+
+#[async_trait]
+impl ToolHandler for GitRevertTool {
+    fn name(&self) -> &'static str {
+        "git_revert"
+    }
+
+    fn description(&self) -> &'static str {
+        "Create commits that undo existing commits, or continue, skip or abort a revert"
+    }
+
+    fn schema(&self) -> Value {
+        serde_json::to_value(schema_for!(GitRevertToolParams)).unwrap_or_default()
+    }
+
+    async fn call(&self, params: Value) -> Result<Value, ToolError> {
+        let params: GitRevertToolParams =
+            serde_json::from_value(params).map_err(|e| ToolError::ExecutionError(e.to_string()))?;
+
+        let mainline = if params.mainline == 0 {
+            None
+        } else {
+            Some(params.mainline)
+        };
+
+        let options = SequencerOptions {
+            mainline,
+            no_commit: params.no_commit,
+        };
+
+        git_revert(params.repo_path, params.action, params.commits, options).await
+    }
+}
+
+pub async fn git_revert(
+    repo_path: String,
+    action: SequencerAction,
+    commits: Vec<String>,
+    options: SequencerOptions,
+) -> Result<Value, ToolError> {
+    run_sequencer(&repo_path, "revert", action, &commits, &options, &[])
+}
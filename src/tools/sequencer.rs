@@ -0,0 +1,149 @@
+//! Shared handling for commands that replay commits through git's sequencer
+//! (cherry-pick and revert)
+
+use crate::tools::status::{operation_in_progress, read_status};
+use crate::tools::{
+    parse_overwritten_paths, read_head, resolve_rev, run_git_command, run_git_command_with_env,
+};
+use mcp_core::handler::ToolError;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SequencerAction {
+    #[default]
+    Start,
+    Continue,
+    Skip,
+    Abort,
+}
+
+/// Options shared by cherry-pick and revert when starting a sequence
+#[derive(Debug, Default)]
+pub struct SequencerOptions {
+    pub mainline: Option<u32>,
+    pub no_commit: bool,
+}
+
+/// Run `git <command>` for the given commits or ranges, or continue, skip or
+/// abort the sequence in progress, and report the commits it created
+pub(crate) fn run_sequencer(
+    repo_path: &str,
+    command: &str,
+    action: SequencerAction,
+    commits: &[String],
+    options: &SequencerOptions,
+    extra_args: &[&str],
+) -> Result<Value, ToolError> {
+    let mainline = options.mainline.map(|m| m.to_string());
+
+    let mut args = vec![command];
+    match action {
+        SequencerAction::Start => {
+            if commits.is_empty() {
+                return Err(ToolError::ExecutionError(
+                    "At least one commit or range is required".to_string(),
+                ));
+            }
+
+            if let Some(ref parent) = mainline {
+                args.push("--mainline");
+                args.push(parent);
+            }
+
+            if options.no_commit {
+                args.push("--no-commit");
+            }
+
+            args.extend_from_slice(extra_args);
+            args.extend(commits.iter().map(String::as_str));
+        }
+        SequencerAction::Continue => args.push("--continue"),
+        SequencerAction::Skip => args.push("--skip"),
+        SequencerAction::Abort => args.push("--abort"),
+    }
+
+    let old_head = resolve_rev(repo_path, "HEAD")?;
+
+    // Keep the prepared messages instead of opening an editor
+    let output = run_git_command_with_env(repo_path, &args, &[("GIT_EDITOR", "true")])?;
+    let message = format!("{}{}", output.stdout, output.stderr);
+
+    let new_commits = match &old_head {
+        Some(old) if action != SequencerAction::Abort => {
+            let range = format!("{}..HEAD", old);
+            run_git_command(repo_path, &["rev-list", "--reverse", &range])?
+                .lines()
+                .map(str::to_string)
+                .collect()
+        }
+        _ => Vec::new(),
+    };
+
+    if output.success {
+        return Ok(json!({
+            "success": true,
+            "action": action,
+            "old_head": old_head,
+            "head": read_head(repo_path)?,
+            "commits": new_commits,
+            "output": message.trim()
+        }));
+    }
+
+    let overwritten = parse_overwritten_paths(&output.stderr);
+    if !overwritten.is_empty() {
+        return Ok(json!({
+            "success": false,
+            "error": "local_changes_would_be_overwritten",
+            "action": action,
+            "old_head": old_head,
+            "commits": new_commits,
+            "conflicting_paths": overwritten,
+            "message": message.trim()
+        }));
+    }
+
+    let in_progress = operation_in_progress(repo_path)?;
+    let stopped_at = match in_progress {
+        Some("cherry_pick") => resolve_rev(repo_path, "CHERRY_PICK_HEAD")?,
+        Some("revert") => resolve_rev(repo_path, "REVERT_HEAD")?,
+        _ => None,
+    };
+
+    let conflicts = read_status(repo_path, false)?.conflicts();
+    if !conflicts.is_empty() {
+        return Ok(json!({
+            "success": false,
+            "error": "conflict",
+            "action": action,
+            "old_head": old_head,
+            "commits": new_commits,
+            "in_progress": in_progress,
+            "stopped_at": stopped_at,
+            "conflicts": conflicts,
+            "message": message.trim()
+        }));
+    }
+
+    // The commit's changes are already in HEAD; skip it or commit it as empty
+    if message.contains("is now empty") {
+        return Ok(json!({
+            "success": false,
+            "error": "empty_commit",
+            "action": action,
+            "old_head": old_head,
+            "commits": new_commits,
+            "in_progress": in_progress,
+            "stopped_at": stopped_at,
+            "message": message.trim()
+        }));
+    }
+
+    Err(ToolError::ExecutionError(format!(
+        "Git command failed: {}",
+        output.stderr
+    )))
+}
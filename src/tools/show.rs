@@ -0,0 +1,231 @@
+use crate::tools::commit_record::{COMMIT_RECORD_FORMAT, split_commit_records};
+use crate::tools::diff::{parse_raw_numstat, parse_unified_diff};
+use crate::tools::run_git_command;
+use crate::tools::tag::read_tag_object;
+use async_trait::async_trait;
+use mcp_core::handler::{ToolError, ToolHandler};
+use schemars::{JsonSchema, schema_for};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+/// Git show tool implementation
+#[derive(Debug, Default)]
+pub struct GitShowTool;
+
+#[derive(Deserialize, JsonSchema)]
+struct GitShowToolParams {
+    #[schemars(description = "The path to the git repository")]
+    repo_path: String,
+    #[schemars(
+        description = "The commit, tag, tree or blob to show, e.g. 'HEAD~2', 'v1.0' or 'HEAD:src' (defaults to HEAD)"
+    )]
+    #[serde(default)]
+    rev: String,
+    #[schemars(description = "Whether to include the patch of a commit")]
+    #[serde(default)]
+    patch: bool,
+    #[schemars(
+        description = "Whether the patch should be structured files and hunks like git_diff instead of text"
+    )]
+    #[serde(default)]
+    structured: bool,
+}
+
+/// One entry of `ls-tree --long`
+#[derive(Debug, Serialize)]
+pub(crate) struct TreeEntry {
+    pub mode: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub oid: String,
+    pub size: Option<u64>,
+    pub path: String,
+}
+
+#[async_trait]
+impl ToolHandler for GitShowTool {
+    fn name(&self) -> &'static str {
+        "git_show"
+    }
+
+    fn description(&self) -> &'static str {
+        "Show a commit with its metadata, file stats and optional patch, an annotated tag, or a tree listing"
+    }
+
+    fn schema(&self) -> Value {
+        serde_json::to_value(schema_for!(GitShowToolParams)).unwrap_or_default()
+    }
+
+    async fn call(&self, params: Value) -> Result<Value, ToolError> {
+        let params: GitShowToolParams =
+            serde_json::from_value(params).map_err(|e| ToolError::ExecutionError(e.to_string()))?;
+
+        let rev = if params.rev.is_empty() {
+            None
+        } else {
+            Some(params.rev)
+        };
+
+        git_show(params.repo_path, rev, params.patch, params.structured).await
+    }
+}
+
+pub async fn git_show(
+    repo_path: String,
+    rev: Option<String>,
+    patch: bool,
+    structured: bool,
+) -> Result<Value, ToolError> {
+    let rev = rev.unwrap_or_else(|| "HEAD".to_string());
+    let oid = run_git_command(
+        &repo_path,
+        &["rev-parse", "--verify", "--end-of-options", &rev],
+    )?;
+    let oid = oid.trim();
+
+    let mut result = show_object(&repo_path, oid, patch, structured)?;
+    result["rev"] = json!(rev);
+    result["oid"] = json!(oid);
+
+    Ok(result)
+}
+
+/// Describe a commit, tag, tree or blob
+fn show_object(
+    repo_path: &str,
+    oid: &str,
+    patch: bool,
+    structured: bool,
+) -> Result<Value, ToolError> {
+    let object_type = run_git_command(repo_path, &["cat-file", "-t", oid])?;
+
+    match object_type.trim() {
+        "commit" => show_commit(repo_path, oid, patch, structured),
+        // Like `git show`, describe an annotated tag and then what it points
+        // at, which may itself be another tag
+        "tag" => {
+            let tag = read_tag_object(repo_path, oid)?;
+            let mut result = show_object(repo_path, &tag.target_oid, patch, structured)?;
+            result["tag"] = json!(tag);
+            result["type"] = json!("tag");
+            Ok(result)
+        }
+        "tree" => {
+            let tree_output = run_git_command(repo_path, &["ls-tree", "-z", "--long", oid])?;
+            Ok(json!({ "type": "tree", "entries": parse_ls_tree(&tree_output) }))
+        }
+        other => {
+            let size = run_git_command(repo_path, &["cat-file", "-s", oid])?;
+            Ok(json!({ "type": other, "size": size.trim().parse::<u64>().ok() }))
+        }
+    }
+}
+
+fn show_commit(
+    repo_path: &str,
+    oid: &str,
+    patch: bool,
+    structured: bool,
+) -> Result<Value, ToolError> {
+    // Merges are described against their first parent, as `git log -p` would.
+    // No --no-abbrev here: it would also turn %h into a full hash.
+    let show_output = run_git_command(
+        repo_path,
+        &[
+            "show",
+            COMMIT_RECORD_FORMAT,
+            "--raw",
+            "--numstat",
+            "-z",
+            "-M",
+            "--diff-merges=first-parent",
+            oid,
+        ],
+    )?;
+
+    let Some((commit, changes)) = split_commit_records(&show_output).next() else {
+        return Err(ToolError::ExecutionError(format!(
+            "Failed to read commit {}",
+            oid
+        )));
+    };
+
+    let patch = if patch {
+        let patch_output = run_git_command(
+            repo_path,
+            &[
+                "show",
+                "--format=",
+                "--no-color",
+                "--no-ext-diff",
+                "-M",
+                "--diff-merges=first-parent",
+                oid,
+            ],
+        )?;
+
+        if structured {
+            json!(parse_unified_diff(&patch_output))
+        } else {
+            json!(patch_output)
+        }
+    } else {
+        Value::Null
+    };
+
+    Ok(json!({
+        "type": "commit",
+        "commit": commit,
+        "signature": read_signature(repo_path, oid)?,
+        "files": parse_raw_numstat(changes),
+        "patch": patch
+    }))
+}
+
+/// Verify the commit signature, if there is one
+fn read_signature(repo_path: &str, oid: &str) -> Result<Value, ToolError> {
+    let signature = run_git_command(
+        repo_path,
+        &["show", "--no-patch", "--format=%G?%x00%GS%x00%GK", oid],
+    )?;
+    let mut fields = signature.trim_end_matches('\n').split('\0');
+
+    let status = match fields.next().unwrap_or_default() {
+        "G" => "good",
+        "B" => "bad",
+        "U" => "good_unknown_validity",
+        "X" => "good_expired",
+        "Y" => "good_expired_key",
+        "R" => "good_revoked_key",
+        "E" => "unverifiable",
+        _ => "unsigned",
+    };
+
+    let non_empty = |field: Option<&str>| field.filter(|f| !f.is_empty()).map(str::to_string);
+
+    Ok(json!({
+        "status": status,
+        "signer": non_empty(fields.next()),
+        "key": non_empty(fields.next())
+    }))
+}
+
+/// Parse NUL-delimited `ls-tree --long` output
+pub(crate) fn parse_ls_tree(output: &str) -> Vec<TreeEntry> {
+    output.split('\0').filter_map(parse_ls_tree_entry).collect()
+}
+
+/// Parse one `ls-tree --long` record: `<mode> <type> <oid> <size>\t<path>`,
+/// where trees have a size of `-`
+pub(crate) fn parse_ls_tree_entry(record: &str) -> Option<TreeEntry> {
+    let (info, path) = record.split_once('\t')?;
+    let mut fields = info.split_whitespace();
+
+    Some(TreeEntry {
+        mode: fields.next()?.to_string(),
+        kind: fields.next()?.to_string(),
+        oid: fields.next()?.to_string(),
+        size: fields.next().and_then(|size| size.parse().ok()),
+        path: path.to_string(),
+    })
+}
@@ -0,0 +1,298 @@
+use crate::tools::diff::parse_unified_diff;
+use crate::tools::status::read_status;
+use crate::tools::{parse_overwritten_paths, run_git_command, run_git_command_output};
+use async_trait::async_trait;
+use mcp_core::handler::{ToolError, ToolHandler};
+use schemars::{JsonSchema, schema_for};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+/// Git stash tool implementation
+#[derive(Debug, Default)]
+pub struct GitStashTool;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum StashAction {
+    Push,
+    List,
+    Show,
+    Apply,
+    Pop,
+    Drop,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct GitStashToolParams {
+    #[schemars(description = "The path to the git repository")]
+    repo_path: String,
+    #[schemars(description = "The stash operation to perform")]
+    action: StashAction,
+    #[schemars(
+        description = "The stash entry to use, e.g. 'stash@{1}' or '1' (defaults to the latest)"
+    )]
+    #[serde(default)]
+    stash: String,
+    #[schemars(description = "The message to record when pushing")]
+    #[serde(default)]
+    message: String,
+    #[schemars(description = "Whether to also stash untracked files when pushing")]
+    #[serde(default)]
+    include_untracked: bool,
+    #[schemars(description = "Limit the push to these paths")]
+    #[serde(default)]
+    paths: Vec<String>,
+    #[schemars(description = "Whether to also restore the staged state when applying or popping")]
+    #[serde(default)]
+    index: bool,
+    #[schemars(
+        description = "Whether show should return structured files and hunks like git_diff"
+    )]
+    #[serde(default)]
+    structured: bool,
+}
+
+/// One entry from `stash list`
+#[derive(Debug, Serialize)]
+struct StashEntry {
+    index: usize,
+    #[serde(rename = "ref")]
+    stash_ref: String,
+    oid: String,
+    branch: Option<String>,
+    message: String,
+    date: String,
+}
+
+#[async_trait]
+impl ToolHandler for GitStashTool {
+    fn name(&self) -> &'static str {
+        "git_stash"
+    }
+
+    fn description(&self) -> &'static str {
+        "Stash local changes, or list, show, apply, pop and drop stash entries"
+    }
+
+    fn schema(&self) -> Value {
+        serde_json::to_value(schema_for!(GitStashToolParams)).unwrap_or_default()
+    }
+
+    async fn call(&self, params: Value) -> Result<Value, ToolError> {
+        let params: GitStashToolParams =
+            serde_json::from_value(params).map_err(|e| ToolError::ExecutionError(e.to_string()))?;
+
+        let stash = if params.stash.is_empty() {
+            None
+        } else {
+            Some(params.stash)
+        };
+
+        let message = if params.message.is_empty() {
+            None
+        } else {
+            Some(params.message)
+        };
+
+        match params.action {
+            StashAction::Push => {
+                git_stash_push(
+                    params.repo_path,
+                    message,
+                    params.include_untracked,
+                    params.paths,
+                )
+                .await
+            }
+            StashAction::List => git_stash_list(params.repo_path).await,
+            StashAction::Show => git_stash_show(params.repo_path, stash, params.structured).await,
+            StashAction::Apply | StashAction::Pop | StashAction::Drop => {
+                git_stash_apply(params.repo_path, params.action, stash, params.index).await
+            }
+        }
+    }
+}
+
+pub async fn git_stash_push(
+    repo_path: String,
+    message: Option<String>,
+    include_untracked: bool,
+    paths: Vec<String>,
+) -> Result<Value, ToolError> {
+    let before = read_stash_list(&repo_path)?.len();
+
+    let mut args = vec!["stash", "push"];
+
+    if let Some(ref msg) = message {
+        args.push("--message");
+        args.push(msg);
+    }
+
+    if include_untracked {
+        args.push("--include-untracked");
+    }
+
+    if !paths.is_empty() {
+        args.push("--");
+        args.extend(paths.iter().map(|p| p.as_str()));
+    }
+
+    let push_output = run_git_command(&repo_path, &args)?;
+
+    // "No local changes to save" exits successfully without creating an entry
+    let mut entries = read_stash_list(&repo_path)?;
+    let created = entries.len() > before;
+
+    Ok(json!({
+        "success": true,
+        "created": created,
+        "entry": if created { entries.drain(..1).next() } else { None },
+        "output": push_output.trim()
+    }))
+}
+
+pub async fn git_stash_list(repo_path: String) -> Result<Value, ToolError> {
+    Ok(json!({
+        "stashes": read_stash_list(&repo_path)?
+    }))
+}
+
+pub async fn git_stash_show(
+    repo_path: String,
+    stash: Option<String>,
+    structured: bool,
+) -> Result<Value, ToolError> {
+    let stash_ref = stash_ref(stash);
+
+    let show_output = run_git_command(
+        &repo_path,
+        &[
+            "stash",
+            "show",
+            "--patch",
+            "--no-color",
+            "--no-ext-diff",
+            &stash_ref,
+        ],
+    )?;
+
+    if structured {
+        return Ok(json!({
+            "stash": stash_ref,
+            "files": parse_unified_diff(&show_output)
+        }));
+    }
+
+    Ok(json!({
+        "stash": stash_ref,
+        "changes": show_output
+    }))
+}
+
+/// Apply, pop or drop a stash entry, reporting conflicts as data
+pub async fn git_stash_apply(
+    repo_path: String,
+    action: StashAction,
+    stash: Option<String>,
+    index: bool,
+) -> Result<Value, ToolError> {
+    let stash_ref = stash_ref(stash);
+
+    let mut args = match action {
+        StashAction::Pop => vec!["stash", "pop"],
+        StashAction::Drop => vec!["stash", "drop"],
+        _ => vec!["stash", "apply"],
+    };
+
+    if index && action != StashAction::Drop {
+        args.push("--index");
+    }
+
+    args.push(&stash_ref);
+
+    let output = run_git_command_output(&repo_path, &args)?;
+
+    if !output.success {
+        let overwritten = parse_overwritten_paths(&output.stderr);
+        if !overwritten.is_empty() {
+            return Ok(json!({
+                "success": false,
+                "stash": stash_ref,
+                "error": "local_changes_would_be_overwritten",
+                "message": output.stderr.trim(),
+                "conflicting_paths": overwritten
+            }));
+        }
+
+        // A conflicting apply leaves unmerged paths behind and, for pop, keeps the entry
+        let conflicts = read_status(&repo_path, false)?.conflicts();
+
+        if conflicts.is_empty() {
+            return Err(ToolError::ExecutionError(format!(
+                "Git command failed: {}",
+                output.stderr
+            )));
+        }
+
+        return Ok(json!({
+            "success": false,
+            "stash": stash_ref,
+            "error": "conflict",
+            "conflicts": conflicts,
+            "stash_kept": true,
+            "message": format!("{}{}", output.stdout, output.stderr).trim()
+        }));
+    }
+
+    Ok(json!({
+        "success": true,
+        "stash": stash_ref,
+        "action": action,
+        "output": format!("{}{}", output.stdout, output.stderr).trim()
+    }))
+}
+
+/// Normalise a stash selector, accepting a bare index such as "2"
+fn stash_ref(stash: Option<String>) -> String {
+    match stash {
+        Some(s) if s.chars().all(|c| c.is_ascii_digit()) => format!("stash@{{{}}}", s),
+        Some(s) => s,
+        None => "stash@{0}".to_string(),
+    }
+}
+
+fn read_stash_list(repo_path: &str) -> Result<Vec<StashEntry>, ToolError> {
+    let list_output = run_git_command(
+        repo_path,
+        &["stash", "list", "--format=%gd%x00%H%x00%gs%x00%cI"],
+    )?;
+
+    Ok(list_output
+        .lines()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            let fields: Vec<&str> = line.splitn(4, '\0').collect();
+            if fields.len() != 4 {
+                return None;
+            }
+
+            // The reflog subject reads "On <branch>: <msg>" or "WIP on <branch>: <oid> <subject>"
+            let subject = fields[2];
+            let (branch, message) = subject
+                .strip_prefix("WIP on ")
+                .or_else(|| subject.strip_prefix("On "))
+                .and_then(|rest| rest.split_once(": "))
+                .map(|(branch, message)| (Some(branch.to_string()), message.to_string()))
+                .unwrap_or((None, subject.to_string()));
+
+            Some(StashEntry {
+                index,
+                stash_ref: fields[0].to_string(),
+                oid: fields[1].to_string(),
+                branch,
+                message,
+                date: fields[3].to_string(),
+            })
+        })
+        .collect())
+}
@@ -2,8 +2,9 @@ use crate::tools::run_git_command;
 use async_trait::async_trait;
 use mcp_core::handler::{ToolError, ToolHandler};
 use schemars::{JsonSchema, schema_for};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::{Value, json};
+use std::path::Path;
 
 /// Git status tool implementation
 #[derive(Debug, Default)]
@@ -13,6 +14,9 @@ pub struct GitStatusTool;
 struct GitStatusToolParams {
     #[schemars(description = "The path to the git repository")]
     repo_path: String,
+    #[schemars(description = "Whether to also list ignored files")]
+    #[serde(default)]
+    include_ignored: bool,
 }
 
 #[async_trait]
@@ -33,16 +37,324 @@ impl ToolHandler for GitStatusTool {
         let params: GitStatusToolParams =
             serde_json::from_value(params).map_err(|e| ToolError::ExecutionError(e.to_string()))?;
 
-        git_status(params.repo_path).await
+        git_status(params.repo_path, params.include_ignored).await
     }
 }
 
-pub async fn git_status(repo_path: String) -> Result<Value, ToolError> {
-    let status_output = run_git_command(&repo_path, &["status", "--porcelain"])?;
-    let status_lines: Vec<&str> = status_output.lines().collect();
+pub async fn git_status(repo_path: String, include_ignored: bool) -> Result<Value, ToolError> {
+    let status = read_status(&repo_path, include_ignored)?;
 
     Ok(json!({
-        "status": status_lines,
-        "is_clean": status_lines.is_empty()
+        "branch": status.branch,
+        "entries": status.entries,
+        "is_clean": status.is_clean()
     }))
 }
+
+/// Branch header reported by `status --branch`
+#[derive(Debug, Default, Serialize)]
+pub(crate) struct StatusBranch {
+    pub head: Option<String>,
+    pub oid: Option<String>,
+    pub detached: bool,
+    pub upstream: Option<String>,
+    pub ahead: Option<u32>,
+    pub behind: Option<u32>,
+}
+
+/// Which kind of porcelain v2 record an entry came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum StatusEntryKind {
+    Ordinary,
+    Renamed,
+    Copied,
+    Unmerged,
+    Untracked,
+    Ignored,
+}
+
+/// Submodule state from the `S<c><m><u>` field
+#[derive(Debug, Serialize)]
+pub(crate) struct SubmoduleState {
+    pub commit_changed: bool,
+    pub tracked_changes: bool,
+    pub untracked_changes: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct StatusEntry {
+    pub kind: StatusEntryKind,
+    pub path: String,
+    pub orig_path: Option<String>,
+    pub index: Option<&'static str>,
+    pub worktree: Option<&'static str>,
+    pub score: Option<u32>,
+    pub conflict: Option<&'static str>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub stages: Vec<IndexStage>,
+    pub submodule: Option<SubmoduleState>,
+}
+
+/// One index stage of an unmerged path
+#[derive(Debug, Serialize)]
+pub(crate) struct IndexStage {
+    pub stage: u8,
+    pub side: &'static str,
+    pub mode: String,
+    pub oid: String,
+}
+
+impl IndexStage {
+    pub fn new(stage: u8, mode: &str, oid: &str) -> Self {
+        let side = match stage {
+            1 => "base",
+            2 => "ours",
+            3 => "theirs",
+            _ => "merged",
+        };
+
+        IndexStage {
+            stage,
+            side,
+            mode: mode.to_string(),
+            oid: oid.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct RepoStatus {
+    pub branch: StatusBranch,
+    pub entries: Vec<StatusEntry>,
+}
+
+impl RepoStatus {
+    /// Whether there is nothing to commit and nothing untracked
+    pub fn is_clean(&self) -> bool {
+        self.entries
+            .iter()
+            .all(|entry| entry.kind == StatusEntryKind::Ignored)
+    }
+
+    /// Unmerged paths with the kind of conflict and the index stages of each
+    pub fn conflicts(&self) -> Vec<Value> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.kind == StatusEntryKind::Unmerged)
+            .map(|entry| {
+                json!({
+                    "path": entry.path,
+                    "conflict": entry.conflict,
+                    "stages": entry.stages
+                })
+            })
+            .collect()
+    }
+}
+
+/// Name the multi-step operation (merge, rebase, ...) that is waiting to be
+/// continued or aborted, if any
+pub(crate) fn operation_in_progress(repo_path: &str) -> Result<Option<&'static str>, ToolError> {
+    const MARKERS: [(&str, &str); 5] = [
+        ("rebase-merge", "rebase"),
+        ("rebase-apply", "rebase"),
+        ("MERGE_HEAD", "merge"),
+        ("CHERRY_PICK_HEAD", "cherry_pick"),
+        ("REVERT_HEAD", "revert"),
+    ];
+
+    let mut args = vec!["rev-parse"];
+    for (marker, _) in MARKERS {
+        args.push("--git-path");
+        args.push(marker);
+    }
+
+    let paths = run_git_command(repo_path, &args)?;
+
+    Ok(paths
+        .lines()
+        .zip(MARKERS)
+        .find(|(path, _)| Path::new(repo_path).join(path).exists())
+        .map(|(_, (_, operation))| operation))
+}
+
+/// Run `status --porcelain=v2 --branch -z` and parse the result
+pub(crate) fn read_status(repo_path: &str, include_ignored: bool) -> Result<RepoStatus, ToolError> {
+    let mut args = vec!["status", "--porcelain=v2", "--branch", "-z"];
+
+    if include_ignored {
+        args.push("--ignored");
+    }
+
+    let status_output = run_git_command(repo_path, &args)?;
+
+    Ok(parse_porcelain_v2(&status_output))
+}
+
+/// Parse NUL-delimited porcelain v2 output
+pub(crate) fn parse_porcelain_v2(output: &str) -> RepoStatus {
+    let mut status = RepoStatus::default();
+    let mut records = output.split('\0').filter(|record| !record.is_empty());
+
+    while let Some(record) = records.next() {
+        let Some((tag, rest)) = record.split_once(' ') else {
+            continue;
+        };
+
+        match tag {
+            "#" => parse_branch_header(&mut status.branch, rest),
+            "1" => {
+                // 1 <XY> <sub> <mH> <mI> <mW> <hH> <hI> <path>
+                let fields: Vec<&str> = rest.splitn(8, ' ').collect();
+                if fields.len() == 8 {
+                    status.entries.push(tracked_entry(
+                        StatusEntryKind::Ordinary,
+                        fields[0],
+                        fields[1],
+                        fields[7],
+                    ));
+                }
+            }
+            "2" => {
+                // 2 <XY> <sub> <mH> <mI> <mW> <hH> <hI> <X><score> <path>, then <origPath>
+                let fields: Vec<&str> = rest.splitn(9, ' ').collect();
+                let orig_path = records.next();
+                if fields.len() == 9 {
+                    let kind = if fields[7].starts_with('C') {
+                        StatusEntryKind::Copied
+                    } else {
+                        StatusEntryKind::Renamed
+                    };
+                    let mut entry = tracked_entry(kind, fields[0], fields[1], fields[8]);
+                    entry.score = fields[7].get(1..).and_then(|score| score.parse().ok());
+                    entry.orig_path = orig_path.map(str::to_string);
+                    status.entries.push(entry);
+                }
+            }
+            "u" => {
+                // u <XY> <sub> <m1> <m2> <m3> <mW> <h1> <h2> <h3> <path>
+                let fields: Vec<&str> = rest.splitn(10, ' ').collect();
+                if fields.len() == 10 {
+                    let mut entry =
+                        tracked_entry(StatusEntryKind::Unmerged, fields[0], fields[1], fields[9]);
+                    entry.conflict = conflict_type(fields[0]);
+                    entry.stages = unmerged_stages(&fields[2..5], &fields[6..9]);
+                    status.entries.push(entry);
+                }
+            }
+            "?" | "!" => {
+                let kind = if tag == "?" {
+                    StatusEntryKind::Untracked
+                } else {
+                    StatusEntryKind::Ignored
+                };
+                status.entries.push(StatusEntry {
+                    kind,
+                    path: rest.to_string(),
+                    orig_path: None,
+                    index: None,
+                    worktree: None,
+                    score: None,
+                    conflict: None,
+                    stages: Vec::new(),
+                    submodule: None,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    status
+}
+
+fn parse_branch_header(branch: &mut StatusBranch, header: &str) {
+    let Some((key, value)) = header.split_once(' ') else {
+        return;
+    };
+
+    match key {
+        "branch.oid" if value != "(initial)" => branch.oid = Some(value.to_string()),
+        "branch.head" if value == "(detached)" => branch.detached = true,
+        "branch.head" => branch.head = Some(value.to_string()),
+        "branch.upstream" => branch.upstream = Some(value.to_string()),
+        "branch.ab" => {
+            // branch.ab +<ahead> -<behind>
+            if let Some((ahead, behind)) = value.split_once(' ') {
+                branch.ahead = ahead.trim_start_matches('+').parse().ok();
+                branch.behind = behind.trim_start_matches('-').parse().ok();
+            }
+        }
+        _ => {}
+    }
+}
+
+fn tracked_entry(kind: StatusEntryKind, xy: &str, sub: &str, path: &str) -> StatusEntry {
+    let mut states = xy.chars().map(file_state);
+
+    StatusEntry {
+        kind,
+        path: path.to_string(),
+        orig_path: None,
+        index: states.next(),
+        worktree: states.next(),
+        score: None,
+        conflict: None,
+        stages: Vec::new(),
+        submodule: parse_submodule(sub),
+    }
+}
+
+/// Pair the stage 1-3 modes and oids of an unmerged entry, leaving out the
+/// stages that are absent (mode 000000)
+fn unmerged_stages(modes: &[&str], oids: &[&str]) -> Vec<IndexStage> {
+    modes
+        .iter()
+        .zip(oids)
+        .zip(1..)
+        .filter(|((mode, _), _)| !mode.trim_start_matches('0').is_empty())
+        .map(|((mode, oid), stage)| IndexStage::new(stage, mode, oid))
+        .collect()
+}
+
+/// Map a single porcelain status letter to a readable state
+fn file_state(code: char) -> &'static str {
+    match code {
+        '.' => "unmodified",
+        'M' => "modified",
+        'T' => "type_changed",
+        'A' => "added",
+        'D' => "deleted",
+        'R' => "renamed",
+        'C' => "copied",
+        'U' => "unmerged",
+        _ => "unknown",
+    }
+}
+
+/// Map an unmerged `XY` pair to the kind of conflict it represents
+fn conflict_type(xy: &str) -> Option<&'static str> {
+    match xy {
+        "DD" => Some("both_deleted"),
+        "AU" => Some("added_by_us"),
+        "UD" => Some("deleted_by_them"),
+        "UA" => Some("added_by_them"),
+        "DU" => Some("deleted_by_us"),
+        "AA" => Some("both_added"),
+        "UU" => Some("both_modified"),
+        _ => None,
+    }
+}
+
+fn parse_submodule(sub: &str) -> Option<SubmoduleState> {
+    let flags = sub.strip_prefix('S')?.as_bytes();
+    if flags.len() != 3 {
+        return None;
+    }
+
+    Some(SubmoduleState {
+        commit_changed: flags[0] == b'C',
+        tracked_changes: flags[1] == b'M',
+        untracked_changes: flags[2] == b'U',
+    })
+}
@@ -0,0 +1,216 @@
+use crate::tools::status::read_status;
+use crate::tools::{
+    parse_overwritten_paths, read_head, resolve_rev, run_git_command, run_git_command_output,
+};
+use async_trait::async_trait;
+use mcp_core::handler::{ToolError, ToolHandler};
+use schemars::{JsonSchema, schema_for};
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+/// Git switch tool implementation
+#[derive(Debug, Default)]
+pub struct GitSwitchTool;
+
+#[derive(Deserialize, JsonSchema)]
+struct GitSwitchToolParams {
+    #[schemars(description = "The path to the git repository")]
+    repo_path: String,
+    #[schemars(description = "The branch or commit to switch to")]
+    target: String,
+    #[schemars(description = "Whether to create the branch before switching to it")]
+    #[serde(default)]
+    create: bool,
+    #[schemars(
+        description = "The commit or branch to create the new branch from (defaults to HEAD)"
+    )]
+    #[serde(default)]
+    start_point: String,
+    #[schemars(
+        description = "Whether to detach HEAD at the target; implied when the target is a commit rather than a local branch"
+    )]
+    #[serde(default)]
+    detach: bool,
+    #[schemars(
+        description = "Whether to carry local changes over with a three-way merge (git switch --merge), which may leave conflicts"
+    )]
+    #[serde(default)]
+    merge: bool,
+    #[schemars(
+        description = "Whether to stash local changes, including untracked files, before switching and re-apply them afterwards"
+    )]
+    #[serde(default)]
+    autostash: bool,
+}
+
+#[async_trait]
+impl ToolHandler for GitSwitchTool {
+    fn name(&self) -> &'static str {
+        "git_switch"
+    }
+
+    fn description(&self) -> &'static str {
+        "Switch to a branch or commit, refusing to overwrite local changes by default"
+    }
+
+    fn schema(&self) -> Value {
+        serde_json::to_value(schema_for!(GitSwitchToolParams)).unwrap_or_default()
+    }
+
+    async fn call(&self, params: Value) -> Result<Value, ToolError> {
+        let params: GitSwitchToolParams =
+            serde_json::from_value(params).map_err(|e| ToolError::ExecutionError(e.to_string()))?;
+
+        let start_point = if params.start_point.is_empty() {
+            None
+        } else {
+            Some(params.start_point)
+        };
+
+        git_switch(
+            params.repo_path,
+            params.target,
+            params.create,
+            start_point,
+            params.detach,
+            params.merge,
+            params.autostash,
+        )
+        .await
+    }
+}
+
+pub async fn git_switch(
+    repo_path: String,
+    target: String,
+    create: bool,
+    start_point: Option<String>,
+    detach: bool,
+    merge: bool,
+    autostash: bool,
+) -> Result<Value, ToolError> {
+    if merge && autostash {
+        return Err(ToolError::ExecutionError(
+            "merge and autostash cannot be combined".to_string(),
+        ));
+    }
+
+    let previous = read_head(&repo_path)?;
+
+    // A target that is not a local branch but names a commit can only be
+    // checked out detached; anything else is left to git's own branch guessing
+    let detach = detach
+        || (!create
+            && !ref_exists(&repo_path, &format!("refs/heads/{}", target))?
+            && ref_exists(&repo_path, &format!("{}^{{commit}}", target))?);
+
+    let mut args = vec!["switch"];
+
+    if create {
+        args.push("-c");
+    } else if detach {
+        args.push("--detach");
+    }
+
+    if merge {
+        args.push("--merge");
+    }
+
+    args.push(&target);
+
+    if let Some(ref start) = start_point {
+        args.push(start);
+    }
+
+    // Park local changes, untracked files included, so the switch cannot trip
+    // over them. Only an entry this call created is restored later: with
+    // nothing to save, `stash push` succeeds without creating one.
+    let autostash_oid = if autostash && !read_status(&repo_path, false)?.is_clean() {
+        let before = resolve_rev(&repo_path, "refs/stash")?;
+        run_git_command(
+            &repo_path,
+            &[
+                "stash",
+                "push",
+                "--include-untracked",
+                "--message",
+                "git_switch autostash",
+            ],
+        )?;
+        let after = resolve_rev(&repo_path, "refs/stash")?;
+        after.filter(|oid| before.as_ref() != Some(oid))
+    } else {
+        None
+    };
+
+    let output = run_git_command_output(&repo_path, &args)?;
+
+    if !output.success {
+        if let Some(ref oid) = autostash_oid {
+            run_git_command(&repo_path, &["stash", "apply", "--index", oid])?;
+            drop_stash(&repo_path, oid)?;
+        }
+
+        let overwritten = parse_overwritten_paths(&output.stderr);
+        if overwritten.is_empty() {
+            return Err(ToolError::ExecutionError(format!(
+                "Git command failed: {}",
+                output.stderr
+            )));
+        }
+
+        return Ok(json!({
+            "success": false,
+            "error": "local_changes_would_be_overwritten",
+            "message": output.stderr.trim(),
+            "conflicting_paths": overwritten,
+            "head": previous
+        }));
+    }
+
+    // Re-apply the stash; on conflict it stays in the stash list
+    let autostash_result = match autostash_oid {
+        Some(ref oid) => {
+            let apply = run_git_command_output(&repo_path, &["stash", "apply", oid])?;
+            let conflicts = read_status(&repo_path, false)?.conflicts();
+            if apply.success {
+                drop_stash(&repo_path, oid)?;
+            }
+
+            Some(json!({
+                "applied": apply.success && conflicts.is_empty(),
+                "conflicts": conflicts,
+                "kept_in_stash": !apply.success,
+                "stash_oid": oid
+            }))
+        }
+        None => None,
+    };
+
+    Ok(json!({
+        "success": true,
+        "previous": previous,
+        "head": read_head(&repo_path)?,
+        "autostash": autostash_result,
+        "output": format!("{}{}", output.stdout, output.stderr).trim()
+    }))
+}
+
+/// Drop the stash entry with the given oid, wherever it now is in the list
+fn drop_stash(repo_path: &str, oid: &str) -> Result<(), ToolError> {
+    let reflog = run_git_command(repo_path, &["log", "-g", "--format=%H", "refs/stash"])?;
+
+    if let Some(index) = reflog.lines().position(|line| line == oid) {
+        run_git_command(
+            repo_path,
+            &["stash", "drop", &format!("stash@{{{}}}", index)],
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Whether a ref or revision expression resolves
+fn ref_exists(repo_path: &str, rev: &str) -> Result<bool, ToolError> {
+    Ok(run_git_command_output(repo_path, &["rev-parse", "--verify", "--quiet", rev])?.success)
+}
@@ -0,0 +1,308 @@
+use crate::tools::run_git_command;
+use async_trait::async_trait;
+use mcp_core::handler::{ToolError, ToolHandler};
+use schemars::{JsonSchema, schema_for};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+/// Git tag tool implementation
+#[derive(Debug, Default)]
+pub struct GitTagTool;
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TagAction {
+    #[default]
+    List,
+    Create,
+    Delete,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct GitTagToolParams {
+    #[schemars(description = "The path to the git repository")]
+    repo_path: String,
+    #[schemars(description = "The tag operation to perform (defaults to list)")]
+    #[serde(default)]
+    action: TagAction,
+    #[schemars(description = "The tag to create or delete")]
+    #[serde(default)]
+    name: String,
+    #[schemars(description = "The commit or object to tag (defaults to HEAD)")]
+    #[serde(default)]
+    rev: String,
+    #[schemars(description = "The annotation message; creates an annotated tag when set")]
+    #[serde(default)]
+    message: String,
+    #[schemars(description = "Whether to replace an existing tag with the same name")]
+    #[serde(default)]
+    force: bool,
+    #[schemars(description = "Only list tags matching this glob, e.g. 'v1.*'")]
+    #[serde(default)]
+    pattern: String,
+    #[schemars(
+        description = "The for-each-ref sort key (defaults to '-version:refname', newest version first)"
+    )]
+    #[serde(default)]
+    sort: String,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct Tagger {
+    pub name: String,
+    pub email: String,
+    pub date: String,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct TagInfo {
+    pub name: String,
+    pub annotated: bool,
+    pub tag_oid: Option<String>,
+    pub target_oid: String,
+    pub target_type: String,
+    pub tagger: Option<Tagger>,
+    pub message: Option<String>,
+}
+
+#[async_trait]
+impl ToolHandler for GitTagTool {
+    fn name(&self) -> &'static str {
+        "git_tag"
+    }
+
+    fn description(&self) -> &'static str {
+        "List, create or delete tags"
+    }
+
+    fn schema(&self) -> Value {
+        serde_json::to_value(schema_for!(GitTagToolParams)).unwrap_or_default()
+    }
+
+    async fn call(&self, params: Value) -> Result<Value, ToolError> {
+        let params: GitTagToolParams =
+            serde_json::from_value(params).map_err(|e| ToolError::ExecutionError(e.to_string()))?;
+
+        let pattern = if params.pattern.is_empty() {
+            None
+        } else {
+            Some(params.pattern)
+        };
+
+        let sort = if params.sort.is_empty() {
+            None
+        } else {
+            Some(params.sort)
+        };
+
+        let rev = if params.rev.is_empty() {
+            None
+        } else {
+            Some(params.rev)
+        };
+
+        let message = if params.message.is_empty() {
+            None
+        } else {
+            Some(params.message)
+        };
+
+        match params.action {
+            TagAction::List => git_tag_list(params.repo_path, pattern, sort).await,
+            TagAction::Create => {
+                git_tag_create(params.repo_path, params.name, rev, message, params.force).await
+            }
+            TagAction::Delete => git_tag_delete(params.repo_path, params.name).await,
+        }
+    }
+}
+
+/// Fields requested from `for-each-ref`, NUL separated with a record separator per tag
+const TAG_FORMAT: &str = "--format=%(refname:short)%00%(objecttype)%00%(objectname)%00%(*objectname)%00%(*objecttype)%00%(taggername)%00%(taggeremail:trim)%00%(taggerdate:iso-strict)%00%(contents)%1e";
+
+pub async fn git_tag_list(
+    repo_path: String,
+    pattern: Option<String>,
+    sort: Option<String>,
+) -> Result<Value, ToolError> {
+    let tags = read_tags(
+        &repo_path,
+        &format!("refs/tags/{}", pattern.as_deref().unwrap_or("")),
+        sort.as_deref().unwrap_or("-version:refname"),
+    )?;
+
+    Ok(json!({ "tags": tags }))
+}
+
+pub async fn git_tag_create(
+    repo_path: String,
+    name: String,
+    rev: Option<String>,
+    message: Option<String>,
+    force: bool,
+) -> Result<Value, ToolError> {
+    require_name(&name)?;
+
+    let mut args = vec!["tag"];
+
+    if let Some(ref msg) = message {
+        args.push("--annotate");
+        args.push("--message");
+        args.push(msg);
+    }
+
+    if force {
+        args.push("--force");
+    }
+
+    args.push(&name);
+
+    if let Some(ref target) = rev {
+        args.push(target);
+    }
+
+    run_git_command(&repo_path, &args)?;
+
+    let tag = read_tags(&repo_path, &format!("refs/tags/{}", name), "refname")?
+        .into_iter()
+        .find(|tag| tag.name == name);
+
+    Ok(json!({
+        "success": true,
+        "tag": tag
+    }))
+}
+
+pub async fn git_tag_delete(repo_path: String, name: String) -> Result<Value, ToolError> {
+    require_name(&name)?;
+
+    let delete_output = run_git_command(&repo_path, &["tag", "--delete", &name])?;
+
+    Ok(json!({
+        "success": true,
+        "deleted": name,
+        "output": delete_output.trim()
+    }))
+}
+
+fn require_name(name: &str) -> Result<(), ToolError> {
+    if name.is_empty() {
+        return Err(ToolError::ExecutionError(
+            "A tag name is required".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+pub(crate) fn read_tags(
+    repo_path: &str,
+    pattern: &str,
+    sort: &str,
+) -> Result<Vec<TagInfo>, ToolError> {
+    let sort_arg = format!("--sort={}", sort);
+    let tag_output = run_git_command(repo_path, &["for-each-ref", TAG_FORMAT, &sort_arg, pattern])?;
+
+    Ok(tag_output
+        .split('\x1e')
+        .filter_map(|record| {
+            let fields: Vec<&str> = record.trim_start_matches('\n').splitn(9, '\0').collect();
+            if fields.len() != 9 {
+                return None;
+            }
+
+            // Annotated tags point at a tag object; `*` fields describe what it peels to
+            let annotated = fields[1] == "tag";
+            let (target_oid, target_type) = if annotated {
+                (fields[3], fields[4])
+            } else {
+                (fields[2], fields[1])
+            };
+
+            Some(TagInfo {
+                name: fields[0].to_string(),
+                annotated,
+                tag_oid: annotated.then(|| fields[2].to_string()),
+                target_oid: target_oid.to_string(),
+                target_type: target_type.to_string(),
+                tagger: annotated.then(|| Tagger {
+                    name: fields[5].to_string(),
+                    email: fields[6].to_string(),
+                    date: fields[7].to_string(),
+                }),
+                message: annotated.then(|| fields[8].trim_end().to_string()),
+            })
+        })
+        .collect())
+}
+
+/// Read an annotated tag object directly, whether or not a ref points at it
+pub(crate) fn read_tag_object(repo_path: &str, oid: &str) -> Result<TagInfo, ToolError> {
+    let tag_output = run_git_command(repo_path, &["cat-file", "tag", oid])?;
+    let (headers, message) = tag_output.split_once("\n\n").unwrap_or((&tag_output, ""));
+
+    let mut tag = TagInfo {
+        name: String::new(),
+        annotated: true,
+        tag_oid: Some(oid.to_string()),
+        target_oid: String::new(),
+        target_type: String::new(),
+        tagger: None,
+        message: Some(message.trim_end().to_string()),
+    };
+
+    for line in headers.lines() {
+        let Some((key, value)) = line.split_once(' ') else {
+            continue;
+        };
+        match key {
+            "object" => tag.target_oid = value.to_string(),
+            "type" => tag.target_type = value.to_string(),
+            "tag" => tag.name = value.to_string(),
+            "tagger" => tag.tagger = parse_tagger(value),
+            _ => {}
+        }
+    }
+
+    if let Some(ref mut tagger) = tag.tagger {
+        tagger.date = tagger_date(repo_path, oid)?;
+    }
+
+    Ok(tag)
+}
+
+/// Parse the name and email of `Name <email> <unix time> <+hhmm>` from a tag
+/// object header; the date is left for [`tagger_date`]
+fn parse_tagger(value: &str) -> Option<Tagger> {
+    let (name, rest) = value.split_once(" <")?;
+    let (email, _) = rest.split_once("> ")?;
+
+    Some(Tagger {
+        name: name.to_string(),
+        email: email.to_string(),
+        date: String::new(),
+    })
+}
+
+/// The tagger date of a tag object in git's `iso-strict` format. A tag object
+/// need not have a ref, so `for-each-ref` cannot format it; `git show` prints
+/// it in the tag's header block, which ends at the first empty line.
+fn tagger_date(repo_path: &str, oid: &str) -> Result<String, ToolError> {
+    let show_output = run_git_command(
+        repo_path,
+        &[
+            "show",
+            "--no-patch",
+            "--format=medium",
+            "--date=iso-strict",
+            oid,
+        ],
+    )?;
+
+    Ok(show_output
+        .lines()
+        .take_while(|line| !line.is_empty())
+        .find_map(|line| line.strip_prefix("Date:"))
+        .unwrap_or_default()
+        .trim()
+        .to_string())
+}
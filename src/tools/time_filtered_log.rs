@@ -1,3 +1,4 @@
+use crate::tools::commit_record::{COMMIT_RECORD_FORMAT, parse_commit_records};
 use crate::tools::run_git_command;
 use mcp_core::{ToolError, handler::ToolHandler};
 use schemars::{schema_for, JsonSchema};
@@ -85,7 +86,7 @@ pub async fn git_time_filtered_log(
     // Store the basic command parts
     let mut args = Vec::new();
     args.push("log".to_string());
-    args.push("--pretty=format:%H|%an|%ad|%s".to_string());
+    args.push(COMMIT_RECORD_FORMAT.to_string());
     
     // Add time range filters
     args.push(format!("--since={}", since));
@@ -111,23 +112,7 @@ pub async fn git_time_filtered_log(
     let log_output = run_git_command(&repo_path, &cmd_args)?;
     
     // Parse the output into structured data
-    let commits: Vec<Value> = log_output
-        .lines()
-        .filter(|line| !line.is_empty())
-        .map(|line| {
-            let parts: Vec<&str> = line.split('|').collect();
-            if parts.len() >= 4 {
-                json!({
-                    "hash": parts[0],
-                    "author": parts[1],
-                    "date": parts[2],
-                    "message": parts[3]
-                })
-            } else {
-                json!({ "raw": line })
-            }
-        })
-        .collect();
+    let commits = parse_commit_records(&log_output);
     
     Ok(json!({
         "commits": commits,